@@ -0,0 +1,569 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter;
+use Atoi;
+use AtoiErr;
+use AtoiResult;
+use AtoiInfoTrack;
+use AtoiOverflow;
+use AtoiPolicy;
+
+///Preference of AnyInt between signed and unsigned types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AnyIntPref {
+	///Non-negative numbers become the smallest unsigned type, negative numbers the smallest signed type.
+	Unsigned,
+	
+	///Numbers become the smallest signed type, `u128` only above `i128::MAX`.
+	Signed,
+}
+
+///Integer of the smallest type able to represent the parsed number.
+///
+///```rust
+///use std::convert::TryFrom;
+///use cluatoi::{AnyInt, AnyIntPref};
+///
+///fn main() {
+///	assert_eq!(AnyInt::atoi(b"200", AnyIntPref::Unsigned), Ok(AnyInt::U8(200)));
+///	assert_eq!(AnyInt::atoi(b"200", AnyIntPref::Signed), Ok(AnyInt::I16(200)));
+///	assert_eq!(AnyInt::atoi(b"-70000", AnyIntPref::Unsigned), Ok(AnyInt::I32(-70000)));
+///
+///	let num = AnyInt::atoi(b"4294967296", AnyIntPref::Unsigned).unwrap();
+///	assert_eq!(num, AnyInt::U64(4294967296));
+///	assert_eq!(u64::try_from(num), Ok(4294967296));
+///	assert_eq!(num.to_i128(), Some(4294967296));
+///}
+///```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AnyInt {
+	U8(u8),
+	U16(u16),
+	U32(u32),
+	U64(u64),
+	U128(u128),
+	
+	I8(i8),
+	I16(i16),
+	I32(i32),
+	I64(i64),
+	I128(i128),
+}
+
+impl AnyInt {
+	///Array parsing into the smallest type.
+	#[inline]
+	pub fn atoi(array: &[u8], pref: AnyIntPref) -> AtoiResult<AnyInt> {
+		AnyInt::atoi_iter(array.iter(), pref)
+	}
+	
+	///Array parsing into the smallest type and stopping on the 'X' character.
+	#[inline]
+	pub fn atoi_stop(array: &[u8], stop: u8, pref: AnyIntPref) -> AtoiResult<AnyInt> {
+		AnyInt::atoi_iter_stop(array.iter(), stop, pref)
+	}
+	
+	///An array analysis into the smallest type waiting for an "X" character even if an error occurred.
+	#[inline]
+	pub fn atoi_wait_stop(array: &[u8], stop: u8, pref: AnyIntPref) -> AtoiResult<AnyInt> {
+		AnyInt::atoi_iter_wait_stop(array.iter(), stop, pref)
+	}
+	
+	///Array parsing into the smallest type using an iterator.
+	pub fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(mut iter: I, pref: AnyIntPref) -> AtoiResult<AnyInt> {
+		match iter.next() {
+			Some(b'-') => AnyInt::from_sign(true, u128::atoi_iter(iter)?, pref),
+			Some(b'+') => AnyInt::from_sign(false, u128::atoi_iter(iter)?, pref),
+			Some(a) => AnyInt::from_sign(false, u128::atoi_iter(iter::once(a).chain(iter))?, pref),
+			None => Ok(AnyInt::from_u128(0, pref)),
+		}
+	}
+	
+	///Array parsing into the smallest type using an iterator and stopping on the 'X' character.
+	///
+	///```rust
+	///use cluatoi::{AnyInt, AnyIntPref};
+	///
+	///fn main() {
+	///	let array = b"1!-1!300";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(AnyInt::atoi_iter_stop(&mut array_iter, b'!', AnyIntPref::Unsigned), Ok(AnyInt::U8(1)));
+	///	assert_eq!(AnyInt::atoi_iter_stop(&mut array_iter, b'!', AnyIntPref::Unsigned), Ok(AnyInt::I8(-1)));
+	///	assert_eq!(AnyInt::atoi_iter_stop(&mut array_iter, b'!', AnyIntPref::Unsigned), Ok(AnyInt::U16(300)));
+	///}
+	///```
+	pub fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8, pref: AnyIntPref) -> AtoiResult<AnyInt> {
+		match iter.next() {
+			Some(b'-') => AnyInt::from_sign(true, u128::atoi_iter_stop(iter, end)?, pref),
+			Some(b'+') => AnyInt::from_sign(false, u128::atoi_iter_stop(iter, end)?, pref),
+			Some(a) => AnyInt::from_sign(false, u128::atoi_iter_stop(iter::once(a).chain(iter), end)?, pref),
+			None => Ok(AnyInt::from_u128(0, pref)),
+		}
+	}
+	
+	///An array analysis into the smallest type using an iterator and waiting for an "X" character even if an error occurred.
+	pub fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8, pref: AnyIntPref) -> AtoiResult<AnyInt> {
+		let (negative, num) = match iter.next() {
+			Some(b'-') => (true, u128::atoi_iter_wait_stop(iter, end)?),
+			Some(b'+') => (false, u128::atoi_iter_wait_stop(iter, end)?),
+			Some(a) => (false, u128::atoi_iter_wait_stop(iter::once(a).chain(iter), end)?),
+			None => (false, 0),
+		};
+		
+		AnyInt::from_sign(negative, num, pref)
+	}
+	
+	//The magnitude of a negative number is at most 2^127.
+	fn from_sign(negative: bool, num: u128, pref: AnyIntPref) -> AtoiResult<AnyInt> {
+		if !negative || num == 0 {
+			return Ok(AnyInt::from_u128(num, pref));
+		}
+		if num > i128::MIN.unsigned_abs() {
+			return Err(AtoiErr::Overflow);
+		}
+		
+		Ok(AnyInt::from_i128((num as i128).wrapping_neg(), pref))
+	}
+	
+	///The smallest type representing the number.
+	pub fn from_u128(num: u128, pref: AnyIntPref) -> AnyInt {
+		match pref {
+			AnyIntPref::Signed if num <= i128::MAX as u128 => AnyInt::from_i128(num as i128, pref),
+			AnyIntPref::Signed => AnyInt::U128(num),
+			AnyIntPref::Unsigned => {
+				if let Ok(a) = u8::try_from(num) {
+					AnyInt::U8(a)
+				}else if let Ok(a) = u16::try_from(num) {
+					AnyInt::U16(a)
+				}else if let Ok(a) = u32::try_from(num) {
+					AnyInt::U32(a)
+				}else if let Ok(a) = u64::try_from(num) {
+					AnyInt::U64(a)
+				}else {
+					AnyInt::U128(num)
+				}
+			},
+		}
+	}
+	
+	///The smallest type representing the number.
+	pub fn from_i128(num: i128, pref: AnyIntPref) -> AnyInt {
+		if num >= 0 && pref == AnyIntPref::Unsigned {
+			return AnyInt::from_u128(num as u128, pref);
+		}
+		
+		if let Ok(a) = i8::try_from(num) {
+			AnyInt::I8(a)
+		}else if let Ok(a) = i16::try_from(num) {
+			AnyInt::I16(a)
+		}else if let Ok(a) = i32::try_from(num) {
+			AnyInt::I32(a)
+		}else if let Ok(a) = i64::try_from(num) {
+			AnyInt::I64(a)
+		}else {
+			AnyInt::I128(num)
+		}
+	}
+	
+	///The number as `i128`, `None` for `u128` values above `i128::MAX`.
+	pub fn to_i128(&self) -> Option<i128> {
+		match *self {
+			AnyInt::U128(a) => i128::try_from(a).ok(),
+			AnyInt::U8(a) => Some(a.into()),
+			AnyInt::U16(a) => Some(a.into()),
+			AnyInt::U32(a) => Some(a.into()),
+			AnyInt::U64(a) => Some(a.into()),
+			AnyInt::I8(a) => Some(a.into()),
+			AnyInt::I16(a) => Some(a.into()),
+			AnyInt::I32(a) => Some(a.into()),
+			AnyInt::I64(a) => Some(a.into()),
+			AnyInt::I128(a) => Some(a),
+		}
+	}
+	
+	///The number as `u128`, `None` for negative values.
+	pub fn to_u128(&self) -> Option<u128> {
+		match *self {
+			AnyInt::U8(a) => Some(a.into()),
+			AnyInt::U16(a) => Some(a.into()),
+			AnyInt::U32(a) => Some(a.into()),
+			AnyInt::U64(a) => Some(a.into()),
+			AnyInt::U128(a) => Some(a),
+			_ => self.to_i128().and_then(|a| u128::try_from(a).ok()),
+		}
+	}
+	
+	///`true` if the number is less than zero.
+	pub fn is_negative(&self) -> bool {
+		match *self {
+			AnyInt::I8(a) => a < 0,
+			AnyInt::I16(a) => a < 0,
+			AnyInt::I32(a) => a < 0,
+			AnyInt::I64(a) => a < 0,
+			AnyInt::I128(a) => a < 0,
+			_ => false,
+		}
+	}
+}
+
+///Type of the value of AnyInt.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AnyIntKind {
+	U8,
+	U16,
+	U32,
+	U64,
+	U128,
+	
+	I8,
+	I16,
+	I32,
+	I64,
+	I128,
+}
+
+impl AnyInt {
+	///Type of the value.
+	pub fn kind(&self) -> AnyIntKind {
+		match *self {
+			AnyInt::U8(_) => AnyIntKind::U8,
+			AnyInt::U16(_) => AnyIntKind::U16,
+			AnyInt::U32(_) => AnyIntKind::U32,
+			AnyInt::U64(_) => AnyIntKind::U64,
+			AnyInt::U128(_) => AnyIntKind::U128,
+			AnyInt::I8(_) => AnyIntKind::I8,
+			AnyInt::I16(_) => AnyIntKind::I16,
+			AnyInt::I32(_) => AnyIntKind::I32,
+			AnyInt::I64(_) => AnyIntKind::I64,
+			AnyInt::I128(_) => AnyIntKind::I128,
+		}
+	}
+}
+
+impl fmt::Display for AnyIntKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match *self {
+			AnyIntKind::U8 => "u8",
+			AnyIntKind::U16 => "u16",
+			AnyIntKind::U32 => "u32",
+			AnyIntKind::U64 => "u64",
+			AnyIntKind::U128 => "u128",
+			AnyIntKind::I8 => "i8",
+			AnyIntKind::I16 => "i16",
+			AnyIntKind::I32 => "i32",
+			AnyIntKind::I64 => "i64",
+			AnyIntKind::I128 => "i128",
+		})
+	}
+}
+
+impl fmt::Display for AnyInt {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			AnyInt::U8(a) => fmt::Display::fmt(&a, f),
+			AnyInt::U16(a) => fmt::Display::fmt(&a, f),
+			AnyInt::U32(a) => fmt::Display::fmt(&a, f),
+			AnyInt::U64(a) => fmt::Display::fmt(&a, f),
+			AnyInt::U128(a) => fmt::Display::fmt(&a, f),
+			AnyInt::I8(a) => fmt::Display::fmt(&a, f),
+			AnyInt::I16(a) => fmt::Display::fmt(&a, f),
+			AnyInt::I32(a) => fmt::Display::fmt(&a, f),
+			AnyInt::I64(a) => fmt::Display::fmt(&a, f),
+			AnyInt::I128(a) => fmt::Display::fmt(&a, f),
+		}
+	}
+}
+
+macro_rules! any_int_build_convert {
+	($($t:ident($v:ident)),+) => {
+		$(
+		impl From<$t> for AnyInt {
+			#[inline(always)]
+			fn from(a: $t) -> Self {
+				AnyInt::$v(a)
+			}
+		}
+		
+		impl TryFrom<AnyInt> for $t {
+			type Error = AtoiErr;
+			
+			///Any variant whose value fits, otherwise `AtoiErr::Overflow`.
+			fn try_from(a: AnyInt) -> Result<Self, Self::Error> {
+				if let AnyInt::$v(a) = a {
+					return Ok(a);
+				}
+				
+				let num = match a.to_i128() {
+					Some(num) => $t::try_from(num).ok(),
+					None => a.to_u128().and_then(|num| $t::try_from(num).ok()),
+				};
+				num.ok_or(AtoiErr::Overflow)
+			}
+		}
+		)+
+	};
+}
+
+any_int_build_convert!(u8(U8), u16(U16), u32(U32), u64(U64), u128(U128));
+any_int_build_convert!(i8(I8), i16(I16), i32(I32), i64(I64), i128(I128));
+
+//usize and isize are at most 64 bits wide on the supported targets.
+impl From<usize> for AnyInt {
+	#[inline(always)]
+	fn from(a: usize) -> Self {
+		AnyInt::U64(a as u64)
+	}
+}
+
+impl From<isize> for AnyInt {
+	#[inline(always)]
+	fn from(a: isize) -> Self {
+		AnyInt::I64(a as i64)
+	}
+}
+
+
+///Error trait AtoiDiagnose.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AtoiDiagErr {
+	///Error other than the overflow of number.
+	Err(AtoiErr),
+	
+	///Overflow of number.
+	Overflow {
+		///Number of digits, including leading zeros.
+		digits: usize,
+		
+		///The narrowest type able to represent the number (signed types are preferred for signed targets), `None` if the number exceeds 128 bits.
+		fits: Option<AnyIntKind>,
+	},
+}
+
+///Parsing with the diagnostics of overflow: the whole number is scanned and the narrowest fitting type is reported.
+///
+///```rust
+///use cluatoi::{AtoiDiagnose, AtoiDiagErr, AnyIntKind};
+///
+///fn main() {
+///	assert_eq!(u16::atoi_diagnose(b"1000"), Ok(1000));
+///	assert_eq!(u16::atoi_diagnose(b"70000"), Err(AtoiDiagErr::Overflow {
+///		digits: 5,
+///		fits: Some(AnyIntKind::U32),
+///	}));
+///	assert_eq!(i16::atoi_diagnose(b"-70000"), Err(AtoiDiagErr::Overflow {
+///		digits: 5,
+///		fits: Some(AnyIntKind::I32),
+///	}));
+///	assert_eq!(u64::atoi_diagnose(b"1000000000000000000000000000000000000000"), Err(AtoiDiagErr::Overflow {
+///		digits: 40,
+///		fits: None,
+///	}));
+///}
+///```
+pub trait AtoiDiagnose<T> {
+	///Array parsing with the diagnostics of overflow.
+	#[inline]
+	fn atoi_diagnose(array: &[u8]) -> Result<T, AtoiDiagErr> {
+		Self::atoi_iter_diagnose(array.iter())
+	}
+	
+	///Array parsing and stopping on the 'X' character with the diagnostics of overflow.
+	#[inline]
+	fn atoi_stop_diagnose(array: &[u8], stop: u8) -> Result<T, AtoiDiagErr> {
+		Self::atoi_iter_stop_diagnose(array.iter(), stop)
+	}
+	
+	///Array parsing using an iterator with the diagnostics of overflow.
+	fn atoi_iter_diagnose<'a, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, AtoiDiagErr>;
+	
+	///Array parsing using an iterator and stopping on the 'X' character with the diagnostics of overflow.
+	///
+	///```rust
+	///use cluatoi::{AtoiDiagnose, AtoiDiagErr, AnyIntKind};
+	///
+	///fn main() {
+	///	let array = b"300!7";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u8::atoi_iter_stop_diagnose(&mut array_iter, b'!'), Err(AtoiDiagErr::Overflow {
+	///		digits: 3,
+	///		fits: Some(AnyIntKind::U16),
+	///	}));
+	///	assert_eq!(array_iter.next(), Some(&b'7'));
+	///}
+	///```
+	fn atoi_iter_stop_diagnose<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, AtoiDiagErr>;
+}
+
+//The magnitude is accumulated in u128 (saturated beyond it), then converted to T.
+fn diagnose<'a, T, I>(iter: I, end: Option<u8>, signed: bool) -> Result<T, AtoiDiagErr>
+	where T: TryFrom<i128> + TryFrom<u128>, I: Iterator<Item=&'a u8> {
+	
+	let mut iter = AtoiInfoTrack::new(iter, end);
+	let (negative, magnitude) = match iter.next() {
+		Some(b'-') if signed => (true, diagnose_magnitude(&mut iter, end)),
+		Some(b'+') if signed => (false, diagnose_magnitude(&mut iter, end)),
+		Some(a) => (false, diagnose_magnitude(iter::once(a).chain(&mut iter), end)),
+		None => (false, Ok((0, false))),
+	};
+	let (num, saturated) = magnitude.map_err(AtoiDiagErr::Err)?;
+	
+	let pref = match signed {
+		true => AnyIntPref::Signed,
+		false => AnyIntPref::Unsigned,
+	};
+	let any = match saturated {
+		true => None,
+		false => AnyInt::from_sign(negative, num, pref).ok(),
+	};
+	
+	let fit = match any.map(|a| a.to_i128()) {
+		Some(Some(a)) => T::try_from(a).ok(),
+		Some(None) => T::try_from(num).ok(),
+		None => None,
+	};
+	match fit {
+		Some(a) => Ok(a),
+		None => Err(AtoiDiagErr::Overflow {
+			digits: iter.info().digits,
+			fits: any.map(|a| a.kind()),
+		}),
+	}
+}
+
+#[inline]
+fn diagnose_magnitude<'a, I: Iterator<Item=&'a u8>>(iter: I, end: Option<u8>) -> AtoiResult<(u128, bool)> {
+	match end {
+		Some(end) => u128::atoi_iter_stop_policy(iter, end, AtoiOverflow::Saturate),
+		None => u128::atoi_iter_policy(iter, AtoiOverflow::Saturate),
+	}
+}
+
+macro_rules! atoi_diagnose_build {
+	(u, $($t:ty),+ ) => {
+		atoi_diagnose_build!(false, $($t),+);
+	};
+	(i, $($t:ty),+ ) => {
+		atoi_diagnose_build!(true, $($t),+);
+	};
+	($signed:expr, $($t:ty),+ ) => {
+		$(
+		impl AtoiDiagnose<$t> for $t {
+			#[inline]
+			fn atoi_iter_diagnose<'a, I: Iterator<Item=&'a u8>>(iter: I) -> Result<$t, AtoiDiagErr> {
+				diagnose(iter, None, $signed)
+			}
+			
+			#[inline]
+			fn atoi_iter_stop_diagnose<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<$t, AtoiDiagErr> {
+				diagnose(iter, Some(end), $signed)
+			}
+		}
+		)+
+	};
+}
+
+atoi_diagnose_build!(u, u8, u16, u32, u64, u128, usize);
+atoi_diagnose_build!(i, i8, i16, i32, i64, i128, isize);
+
+
+#[cfg(test)]
+mod tests {	
+	use super::*;
+	
+	#[test]
+	fn smallest_unsigned() {
+		let pref = AnyIntPref::Unsigned;
+		
+		assert_eq!(AnyInt::atoi(b"0", pref), Result::Ok(AnyInt::U8(0)));
+		assert_eq!(AnyInt::atoi(b"-0", pref), Result::Ok(AnyInt::U8(0)));
+		assert_eq!(AnyInt::atoi(b"255", pref), Result::Ok(AnyInt::U8(255)));
+		assert_eq!(AnyInt::atoi(b"+256", pref), Result::Ok(AnyInt::U16(256)));
+		assert_eq!(AnyInt::atoi(b"65536", pref), Result::Ok(AnyInt::U32(65536)));
+		assert_eq!(AnyInt::atoi(b"18446744073709551615", pref), Result::Ok(AnyInt::U64(18446744073709551615)));
+		assert_eq!(AnyInt::atoi(b"18446744073709551616", pref), Result::Ok(AnyInt::U128(18446744073709551616)));
+		assert_eq!(AnyInt::atoi(b"340282366920938463463374607431768211456", pref), Result::Err(AtoiErr::Overflow));
+		
+		assert_eq!(AnyInt::atoi(b"-128", pref), Result::Ok(AnyInt::I8(-128)));
+		assert_eq!(AnyInt::atoi(b"-129", pref), Result::Ok(AnyInt::I16(-129)));
+		assert_eq!(AnyInt::atoi(b"-170141183460469231731687303715884105728", pref), Result::Ok(AnyInt::I128(i128::MIN)));
+		assert_eq!(AnyInt::atoi(b"-170141183460469231731687303715884105729", pref), Result::Err(AtoiErr::Overflow));
+		
+		assert_eq!(AnyInt::atoi(b"1x", pref), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(AnyInt::atoi(b"--1", pref), Result::Err(AtoiErr::ByteUnk(b'-')));
+	}
+	
+	#[test]
+	fn smallest_signed() {
+		let pref = AnyIntPref::Signed;
+		
+		assert_eq!(AnyInt::atoi(b"127", pref), Result::Ok(AnyInt::I8(127)));
+		assert_eq!(AnyInt::atoi(b"128", pref), Result::Ok(AnyInt::I16(128)));
+		assert_eq!(AnyInt::atoi(b"-2147483649", pref), Result::Ok(AnyInt::I64(-2147483649)));
+		assert_eq!(AnyInt::atoi(b"170141183460469231731687303715884105727", pref), Result::Ok(AnyInt::I128(i128::MAX)));
+		assert_eq!(AnyInt::atoi(b"170141183460469231731687303715884105728", pref), Result::Ok(AnyInt::U128(1 << 127)));
+	}
+	
+	#[test]
+	fn stop() {
+		let pref = AnyIntPref::Unsigned;
+		
+		let array = b"x1!2!-3";
+		let mut iter = array.iter();
+		assert_eq!(AnyInt::atoi_iter_wait_stop(&mut iter, b'!', pref), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(AnyInt::atoi_iter_stop(&mut iter, b'!', pref), Result::Ok(AnyInt::U8(2)));
+		assert_eq!(AnyInt::atoi_iter_stop(&mut iter, b'!', pref), Result::Ok(AnyInt::I8(-3)));
+		
+		assert_eq!(AnyInt::atoi_stop(b"!", b'!', pref), Result::Ok(AnyInt::U8(0)));
+		assert_eq!(AnyInt::atoi_stop(b"", b'!', pref), Result::Ok(AnyInt::U8(0)));
+	}
+	
+	#[test]
+	fn convert() {
+		assert_eq!(u8::try_from(AnyInt::I64(255)), Result::Ok(255));
+		assert_eq!(u8::try_from(AnyInt::I8(-1)), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i128::try_from(AnyInt::U128(u128::MAX)), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u128::try_from(AnyInt::U128(u128::MAX)), Result::Ok(u128::MAX));
+		assert_eq!(AnyInt::from(-5i16), AnyInt::I16(-5));
+		assert_eq!(AnyInt::from(usize::MAX).to_u128(), Some(usize::MAX as u128));
+		assert_eq!(AnyInt::from(isize::MIN).to_i128(), Some(isize::MIN as i128));
+		
+		assert_eq!(AnyInt::U128(u128::MAX).to_i128(), None);
+		assert_eq!(AnyInt::I8(-1).to_u128(), None);
+		assert_eq!(AnyInt::I8(1).to_u128(), Some(1));
+		assert!(AnyInt::I64(-1).is_negative());
+		assert!(!AnyInt::U8(0).is_negative());
+		
+		assert_eq!(AnyInt::I32(-70000).to_string(), "-70000");
+		assert_eq!(AnyInt::I32(-70000).kind(), AnyIntKind::I32);
+		assert_eq!(AnyIntKind::U128.to_string(), "u128");
+	}
+	
+	#[test]
+	fn diagnose() {
+		let overflow = |digits, fits| Some(AtoiDiagErr::Overflow { digits, fits });
+		
+		assert_eq!(u8::atoi_diagnose(b"255"), Result::Ok(255));
+		assert_eq!(u8::atoi_diagnose(b"256").err(), overflow(3, Some(AnyIntKind::U16)));
+		assert_eq!(u16::atoi_diagnose(b"0070000").err(), overflow(7, Some(AnyIntKind::U32)));
+		assert_eq!(usize::atoi_diagnose(b"340282366920938463463374607431768211455").err(), overflow(39, Some(AnyIntKind::U128)));
+		assert_eq!(u128::atoi_diagnose(b"340282366920938463463374607431768211456").err(), overflow(39, None));
+		
+		assert_eq!(i8::atoi_diagnose(b"-128"), Result::Ok(-128));
+		assert_eq!(i8::atoi_diagnose(b"128").err(), overflow(3, Some(AnyIntKind::I16)));
+		assert_eq!(i8::atoi_diagnose(b"+40000").err(), overflow(5, Some(AnyIntKind::I32)));
+		assert_eq!(i64::atoi_diagnose(b"170141183460469231731687303715884105728").err(), overflow(39, Some(AnyIntKind::U128)));
+		assert_eq!(i128::atoi_diagnose(b"-170141183460469231731687303715884105729").err(), overflow(39, None));
+		assert_eq!(isize::atoi_diagnose(b"-99999999999999999999").err(), overflow(20, Some(AnyIntKind::I128)));
+		
+		//scanning continues after overflow
+		assert_eq!(u8::atoi_diagnose(b"1000x"), Result::Err(AtoiDiagErr::Err(AtoiErr::ByteUnk(b'x'))));
+		assert_eq!(u8::atoi_diagnose(b"-1"), Result::Err(AtoiDiagErr::Err(AtoiErr::ByteUnk(b'-'))));
+		
+		let array = b"99999999999999999999999999999999999999999!1";
+		let mut iter = array.iter();
+		assert_eq!(u32::atoi_iter_stop_diagnose(&mut iter, b'!').err(), overflow(41, None));
+		assert_eq!(u32::atoi_iter_stop_diagnose(&mut iter, b'!'), Result::Ok(1));
+	}
+}
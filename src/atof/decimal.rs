@@ -0,0 +1,291 @@
+//Slow path: the decimal number with up to 768 digits shifted by powers of two, always correctly rounded.
+
+use super::{AtofFloat, BiasedFp};
+
+pub struct Decimal {
+	//Number of the significant digits.
+	pub num_digits: usize,
+	//Value is 0.digits * 10^decimal_point.
+	pub decimal_point: i32,
+	//Nonzero digits were dropped after MAX_DIGITS.
+	pub truncated: bool,
+	pub digits: [u8; Decimal::MAX_DIGITS],
+}
+
+impl Decimal {
+	pub const MAX_DIGITS: usize = 768;
+	const DECIMAL_POINT_RANGE: i32 = 2047;
+	
+	pub fn new() -> Self {
+		Decimal {
+			num_digits: 0,
+			decimal_point: 0,
+			truncated: false,
+			digits: [0; Decimal::MAX_DIGITS],
+		}
+	}
+	
+	pub fn push_digit(&mut self, digit: u8) {
+		if self.num_digits < Self::MAX_DIGITS {
+			self.digits[self.num_digits] = digit;
+			self.num_digits += 1;
+		}else if digit != 0 {
+			self.truncated = true;
+		}
+	}
+	
+	pub fn trim(&mut self) {
+		while self.num_digits != 0 && self.digits[self.num_digits - 1] == 0 {
+			self.num_digits -= 1;
+		}
+	}
+	
+	//Integer part rounded to even.
+	fn round(&self) -> u64 {
+		if self.num_digits == 0 || self.decimal_point < 0 {
+			return 0;
+		}else if self.decimal_point > 18 {
+			return u64::MAX;
+		}
+		let dp = self.decimal_point as usize;
+		let mut n = 0u64;
+		for i in 0..dp {
+			n *= 10;
+			if i < self.num_digits {
+				n += self.digits[i] as u64;
+			}
+		}
+		let mut round_up = false;
+		if dp < self.num_digits {
+			round_up = self.digits[dp] >= 5;
+			if self.digits[dp] == 5 && dp + 1 == self.num_digits {
+				round_up = self.truncated || (dp != 0 && self.digits[dp - 1] & 1 != 0);
+			}
+		}
+		if round_up {
+			n += 1;
+		}
+		n
+	}
+	
+	//self * 2^shift, shift <= 60
+	fn left_shift(&mut self, shift: usize) {
+		if self.num_digits == 0 {
+			return;
+		}
+		let num_new_digits = self.left_shift_digits(shift);
+		let mut read_index = self.num_digits;
+		let mut write_index = self.num_digits + num_new_digits;
+		let mut n = 0u64;
+		while read_index != 0 {
+			read_index -= 1;
+			write_index -= 1;
+			n += (self.digits[read_index] as u64) << shift;
+			let quotient = n / 10;
+			let remainder = n - 10 * quotient;
+			if write_index < Self::MAX_DIGITS {
+				self.digits[write_index] = remainder as u8;
+			}else if remainder > 0 {
+				self.truncated = true;
+			}
+			n = quotient;
+		}
+		while n > 0 {
+			write_index -= 1;
+			let quotient = n / 10;
+			let remainder = n - 10 * quotient;
+			if write_index < Self::MAX_DIGITS {
+				self.digits[write_index] = remainder as u8;
+			}else if remainder > 0 {
+				self.truncated = true;
+			}
+			n = quotient;
+		}
+		self.num_digits = (self.num_digits + num_new_digits).min(Self::MAX_DIGITS);
+		self.decimal_point += num_new_digits as i32;
+		self.trim();
+	}
+	
+	//self / 2^shift, shift <= 60
+	fn right_shift(&mut self, shift: usize) {
+		let mut read_index = 0;
+		let mut write_index = 0;
+		let mut n = 0u64;
+		while n >> shift == 0 {
+			if read_index < self.num_digits {
+				n = 10 * n + self.digits[read_index] as u64;
+				read_index += 1;
+			}else if n == 0 {
+				return;
+			}else {
+				while n >> shift == 0 {
+					n *= 10;
+					read_index += 1;
+				}
+				break;
+			}
+		}
+		self.decimal_point -= read_index as i32 - 1;
+		if self.decimal_point < -Self::DECIMAL_POINT_RANGE {
+			self.num_digits = 0;
+			self.decimal_point = 0;
+			self.truncated = false;
+			return;
+		}
+		let mask = (1u64 << shift) - 1;
+		while read_index < self.num_digits {
+			let new_digit = (n >> shift) as u8;
+			n = 10 * (n & mask) + self.digits[read_index] as u64;
+			read_index += 1;
+			self.digits[write_index] = new_digit;
+			write_index += 1;
+		}
+		while n > 0 {
+			let new_digit = (n >> shift) as u8;
+			n = 10 * (n & mask);
+			if write_index < Self::MAX_DIGITS {
+				self.digits[write_index] = new_digit;
+				write_index += 1;
+			}else if new_digit > 0 {
+				self.truncated = true;
+			}
+		}
+		self.num_digits = write_index;
+		self.trim();
+	}
+	
+	//Number of the new digits after the multiplication by 2^shift: the digits of 2^shift, or one less
+	//if the leading digits are less than 5^shift.
+	fn left_shift_digits(&self, shift: usize) -> usize {
+		let a = LEFT_SHIFT[shift];
+		let b = LEFT_SHIFT[shift + 1];
+		let num_new_digits = (a >> 11) as usize;
+		let pow5 = &LEFT_SHIFT_POW5[(a & 0x7FF) as usize..(b & 0x7FF) as usize];
+		for (i, p5) in pow5.iter().enumerate() {
+			if i >= self.num_digits || self.digits[i] < *p5 {
+				return num_new_digits - 1;
+			}else if self.digits[i] > *p5 {
+				return num_new_digits;
+			}
+		}
+		num_new_digits
+	}
+	
+	//The float nearest to the number, the number is shifted to [1/2, 1) and then to the mantissa bits.
+	pub fn into_float<F: AtofFloat>(mut self) -> BiasedFp {
+		const MAX_SHIFT: usize = 60;
+		const POWERS: [usize; 19] = [0, 3, 6, 9, 13, 16, 19, 23, 26, 29, 33, 36, 39, 43, 46, 49, 53, 56, 59];
+		let get_shift = |n: usize| POWERS.get(n).cloned().unwrap_or(MAX_SHIFT);
+		
+		let zero = BiasedFp { f: 0, e: 0 };
+		let inf = BiasedFp { f: 0, e: F::INFINITE_POWER };
+		
+		if self.num_digits == 0 || self.decimal_point < -324 {
+			return zero;
+		}else if self.decimal_point >= 310 {
+			return inf;
+		}
+		
+		let mut exp2 = 0i32;
+		while self.decimal_point > 0 {
+			let shift = get_shift(self.decimal_point as usize);
+			self.right_shift(shift);
+			if self.decimal_point < -Self::DECIMAL_POINT_RANGE {
+				return zero;
+			}
+			exp2 += shift as i32;
+		}
+		while self.decimal_point <= 0 {
+			let shift = match self.decimal_point {
+				0 => match self.digits[0] {
+					5 ..= 9 => break,
+					0 | 1 => 2,
+					_ => 1,
+				},
+				a => get_shift(-a as usize),
+			};
+			self.left_shift(shift);
+			if self.decimal_point > Self::DECIMAL_POINT_RANGE {
+				return inf;
+			}
+			exp2 -= shift as i32;
+		}
+		
+		//[1/2, 1) to [1, 2)
+		exp2 -= 1;
+		while F::MINIMUM_EXPONENT + 1 > exp2 {
+			let n = ((F::MINIMUM_EXPONENT + 1 - exp2) as usize).min(MAX_SHIFT);
+			self.right_shift(n);
+			exp2 += n as i32;
+		}
+		if exp2 - F::MINIMUM_EXPONENT >= F::INFINITE_POWER {
+			return inf;
+		}
+		
+		self.left_shift(F::MANTISSA_EXPLICIT_BITS as usize + 1);
+		let mut mantissa = self.round();
+		if mantissa >= 1 << (F::MANTISSA_EXPLICIT_BITS + 1) {
+			//Rounding carried into the next bit.
+			self.right_shift(1);
+			exp2 += 1;
+			mantissa = self.round();
+			if exp2 - F::MINIMUM_EXPONENT >= F::INFINITE_POWER {
+				return inf;
+			}
+		}
+		let mut power2 = exp2 - F::MINIMUM_EXPONENT;
+		if mantissa < 1 << F::MANTISSA_EXPLICIT_BITS {
+			power2 -= 1;
+		}
+		mantissa &= (1 << F::MANTISSA_EXPLICIT_BITS) - 1;
+		
+		BiasedFp { f: mantissa, e: power2 }
+	}
+}
+
+//Digits of 2^shift << 11 | offset of the digits of 5^shift in LEFT_SHIFT_POW5.
+const LEFT_SHIFT: [u16; 65] = [
+	0x0000, 0x0800, 0x0801, 0x0803, 0x1006, 0x1009, 0x100D, 0x1812, 0x1817, 0x181D, 0x2024,
+	0x202B, 0x2033, 0x203C, 0x2846, 0x2850, 0x285B, 0x3067, 0x3073, 0x3080, 0x388E, 0x389C,
+	0x38AB, 0x38BB, 0x40CC, 0x40DD, 0x40EF, 0x4902, 0x4915, 0x4929, 0x513E, 0x5153, 0x5169,
+	0x5180, 0x5998, 0x59B0, 0x59C9, 0x61E3, 0x61FD, 0x6218, 0x6A34, 0x6A50, 0x6A6D, 0x6A8B,
+	0x72AA, 0x72C9, 0x72E9, 0x7B0A, 0x7B2B, 0x7B4D, 0x8370, 0x8393, 0x83B7, 0x83DC, 0x8C02,
+	0x8C28, 0x8C4F, 0x9477, 0x949F, 0x94C8, 0x9CF2, 0x051C, 0x051C, 0x051C, 0x051C,
+];
+
+//Digits of 5^1, 5^2, ..., 5^60.
+const LEFT_SHIFT_POW5: [u8; 1308] = [
+	5, 2, 5, 1, 2, 5, 6, 2, 5, 3, 1, 2, 5, 1, 5, 6, 2, 5, 7, 8, 1, 2, 5, 3, 9, 0, 6, 2, 5, 1, 9, 5, 3, 1, 2, 5, 9, 7, 6, 5,
+	6, 2, 5, 4, 8, 8, 2, 8, 1, 2, 5, 2, 4, 4, 1, 4, 0, 6, 2, 5, 1, 2, 2, 0, 7, 0, 3, 1, 2, 5, 6, 1, 0, 3, 5, 1, 5, 6, 2, 5,
+	3, 0, 5, 1, 7, 5, 7, 8, 1, 2, 5, 1, 5, 2, 5, 8, 7, 8, 9, 0, 6, 2, 5, 7, 6, 2, 9, 3, 9, 4, 5, 3, 1, 2, 5, 3, 8, 1, 4, 6,
+	9, 7, 2, 6, 5, 6, 2, 5, 1, 9, 0, 7, 3, 4, 8, 6, 3, 2, 8, 1, 2, 5, 9, 5, 3, 6, 7, 4, 3, 1, 6, 4, 0, 6, 2, 5, 4, 7, 6, 8,
+	3, 7, 1, 5, 8, 2, 0, 3, 1, 2, 5, 2, 3, 8, 4, 1, 8, 5, 7, 9, 1, 0, 1, 5, 6, 2, 5, 1, 1, 9, 2, 0, 9, 2, 8, 9, 5, 5, 0, 7,
+	8, 1, 2, 5, 5, 9, 6, 0, 4, 6, 4, 4, 7, 7, 5, 3, 9, 0, 6, 2, 5, 2, 9, 8, 0, 2, 3, 2, 2, 3, 8, 7, 6, 9, 5, 3, 1, 2, 5, 1,
+	4, 9, 0, 1, 1, 6, 1, 1, 9, 3, 8, 4, 7, 6, 5, 6, 2, 5, 7, 4, 5, 0, 5, 8, 0, 5, 9, 6, 9, 2, 3, 8, 2, 8, 1, 2, 5, 3, 7, 2,
+	5, 2, 9, 0, 2, 9, 8, 4, 6, 1, 9, 1, 4, 0, 6, 2, 5, 1, 8, 6, 2, 6, 4, 5, 1, 4, 9, 2, 3, 0, 9, 5, 7, 0, 3, 1, 2, 5, 9, 3,
+	1, 3, 2, 2, 5, 7, 4, 6, 1, 5, 4, 7, 8, 5, 1, 5, 6, 2, 5, 4, 6, 5, 6, 6, 1, 2, 8, 7, 3, 0, 7, 7, 3, 9, 2, 5, 7, 8, 1, 2,
+	5, 2, 3, 2, 8, 3, 0, 6, 4, 3, 6, 5, 3, 8, 6, 9, 6, 2, 8, 9, 0, 6, 2, 5, 1, 1, 6, 4, 1, 5, 3, 2, 1, 8, 2, 6, 9, 3, 4, 8,
+	1, 4, 4, 5, 3, 1, 2, 5, 5, 8, 2, 0, 7, 6, 6, 0, 9, 1, 3, 4, 6, 7, 4, 0, 7, 2, 2, 6, 5, 6, 2, 5, 2, 9, 1, 0, 3, 8, 3, 0,
+	4, 5, 6, 7, 3, 3, 7, 0, 3, 6, 1, 3, 2, 8, 1, 2, 5, 1, 4, 5, 5, 1, 9, 1, 5, 2, 2, 8, 3, 6, 6, 8, 5, 1, 8, 0, 6, 6, 4, 0,
+	6, 2, 5, 7, 2, 7, 5, 9, 5, 7, 6, 1, 4, 1, 8, 3, 4, 2, 5, 9, 0, 3, 3, 2, 0, 3, 1, 2, 5, 3, 6, 3, 7, 9, 7, 8, 8, 0, 7, 0,
+	9, 1, 7, 1, 2, 9, 5, 1, 6, 6, 0, 1, 5, 6, 2, 5, 1, 8, 1, 8, 9, 8, 9, 4, 0, 3, 5, 4, 5, 8, 5, 6, 4, 7, 5, 8, 3, 0, 0, 7,
+	8, 1, 2, 5, 9, 0, 9, 4, 9, 4, 7, 0, 1, 7, 7, 2, 9, 2, 8, 2, 3, 7, 9, 1, 5, 0, 3, 9, 0, 6, 2, 5, 4, 5, 4, 7, 4, 7, 3, 5,
+	0, 8, 8, 6, 4, 6, 4, 1, 1, 8, 9, 5, 7, 5, 1, 9, 5, 3, 1, 2, 5, 2, 2, 7, 3, 7, 3, 6, 7, 5, 4, 4, 3, 2, 3, 2, 0, 5, 9, 4,
+	7, 8, 7, 5, 9, 7, 6, 5, 6, 2, 5, 1, 1, 3, 6, 8, 6, 8, 3, 7, 7, 2, 1, 6, 1, 6, 0, 2, 9, 7, 3, 9, 3, 7, 9, 8, 8, 2, 8, 1,
+	2, 5, 5, 6, 8, 4, 3, 4, 1, 8, 8, 6, 0, 8, 0, 8, 0, 1, 4, 8, 6, 9, 6, 8, 9, 9, 4, 1, 4, 0, 6, 2, 5, 2, 8, 4, 2, 1, 7, 0,
+	9, 4, 3, 0, 4, 0, 4, 0, 0, 7, 4, 3, 4, 8, 4, 4, 9, 7, 0, 7, 0, 3, 1, 2, 5, 1, 4, 2, 1, 0, 8, 5, 4, 7, 1, 5, 2, 0, 2, 0,
+	0, 3, 7, 1, 7, 4, 2, 2, 4, 8, 5, 3, 5, 1, 5, 6, 2, 5, 7, 1, 0, 5, 4, 2, 7, 3, 5, 7, 6, 0, 1, 0, 0, 1, 8, 5, 8, 7, 1, 1,
+	2, 4, 2, 6, 7, 5, 7, 8, 1, 2, 5, 3, 5, 5, 2, 7, 1, 3, 6, 7, 8, 8, 0, 0, 5, 0, 0, 9, 2, 9, 3, 5, 5, 6, 2, 1, 3, 3, 7, 8,
+	9, 0, 6, 2, 5, 1, 7, 7, 6, 3, 5, 6, 8, 3, 9, 4, 0, 0, 2, 5, 0, 4, 6, 4, 6, 7, 7, 8, 1, 0, 6, 6, 8, 9, 4, 5, 3, 1, 2, 5,
+	8, 8, 8, 1, 7, 8, 4, 1, 9, 7, 0, 0, 1, 2, 5, 2, 3, 2, 3, 3, 8, 9, 0, 5, 3, 3, 4, 4, 7, 2, 6, 5, 6, 2, 5, 4, 4, 4, 0, 8,
+	9, 2, 0, 9, 8, 5, 0, 0, 6, 2, 6, 1, 6, 1, 6, 9, 4, 5, 2, 6, 6, 7, 2, 3, 6, 3, 2, 8, 1, 2, 5, 2, 2, 2, 0, 4, 4, 6, 0, 4,
+	9, 2, 5, 0, 3, 1, 3, 0, 8, 0, 8, 4, 7, 2, 6, 3, 3, 3, 6, 1, 8, 1, 6, 4, 0, 6, 2, 5, 1, 1, 1, 0, 2, 2, 3, 0, 2, 4, 6, 2,
+	5, 1, 5, 6, 5, 4, 0, 4, 2, 3, 6, 3, 1, 6, 6, 8, 0, 9, 0, 8, 2, 0, 3, 1, 2, 5, 5, 5, 5, 1, 1, 1, 5, 1, 2, 3, 1, 2, 5, 7,
+	8, 2, 7, 0, 2, 1, 1, 8, 1, 5, 8, 3, 4, 0, 4, 5, 4, 1, 0, 1, 5, 6, 2, 5, 2, 7, 7, 5, 5, 5, 7, 5, 6, 1, 5, 6, 2, 8, 9, 1,
+	3, 5, 1, 0, 5, 9, 0, 7, 9, 1, 7, 0, 2, 2, 7, 0, 5, 0, 7, 8, 1, 2, 5, 1, 3, 8, 7, 7, 7, 8, 7, 8, 0, 7, 8, 1, 4, 4, 5, 6,
+	7, 5, 5, 2, 9, 5, 3, 9, 5, 8, 5, 1, 1, 3, 5, 2, 5, 3, 9, 0, 6, 2, 5, 6, 9, 3, 8, 8, 9, 3, 9, 0, 3, 9, 0, 7, 2, 2, 8, 3,
+	7, 7, 6, 4, 7, 6, 9, 7, 9, 2, 5, 5, 6, 7, 6, 2, 6, 9, 5, 3, 1, 2, 5, 3, 4, 6, 9, 4, 4, 6, 9, 5, 1, 9, 5, 3, 6, 1, 4, 1,
+	8, 8, 8, 2, 3, 8, 4, 8, 9, 6, 2, 7, 8, 3, 8, 1, 3, 4, 7, 6, 5, 6, 2, 5, 1, 7, 3, 4, 7, 2, 3, 4, 7, 5, 9, 7, 6, 8, 0, 7,
+	0, 9, 4, 4, 1, 1, 9, 2, 4, 4, 8, 1, 3, 9, 1, 9, 0, 6, 7, 3, 8, 2, 8, 1, 2, 5, 8, 6, 7, 3, 6, 1, 7, 3, 7, 9, 8, 8, 4, 0,
+	3, 5, 4, 7, 2, 0, 5, 9, 6, 2, 2, 4, 0, 6, 9, 5, 9, 5, 3, 3, 6, 9, 1, 4, 0, 6, 2, 5,
+];
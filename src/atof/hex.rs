@@ -0,0 +1,251 @@
+//Hexadecimal float `[+-]0x hexdigits[.hexdigits]p[+-]digits` as in C99 and `printf("%a")`, the binary exponent is mandatory.
+
+use AtoiErr;
+use AtoiExp;
+use super::{AtofFloat, AtofParse, BiasedFp};
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+	Sign,
+	Zero,
+	Integer,
+	Fraction,
+	Exponent,
+	ExponentSign,
+	ExponentDigits,
+}
+
+//The value is exactly mantissa * 2^exponent, sticky is a dropped nonzero bit below the mantissa.
+fn to_float<F: AtofFloat>(mantissa: u64, sticky: bool, exponent: i64) -> F {
+	if mantissa == 0 {
+		return F::ZERO;
+	}
+	
+	let lz = mantissa.leading_zeros();
+	let mantissa = mantissa << lz;
+	//The biased exponent of the leading bit.
+	let biased = exponent + 63 - lz as i64 - F::MINIMUM_EXPONENT as i64;
+	if biased >= F::INFINITE_POWER as i64 {
+		return F::INFINITY;
+	}
+	
+	//Subnormal numbers lose the bits below 2^(MINIMUM_EXPONENT + 1 - MANTISSA_EXPLICIT_BITS).
+	let shift = 63 - F::MANTISSA_EXPLICIT_BITS as i64 + match biased < 1 {
+		true => 1 - biased,
+		false => 0,
+	};
+	if shift > 64 {
+		//Below the half of the smallest subnormal.
+		return F::ZERO;
+	}
+	
+	let wide = mantissa as u128;
+	let mut f = (wide >> shift) as u64;
+	let rest = wide & ((1 << shift) - 1);
+	let half = 1 << (shift - 1);
+	if rest > half || rest == half && (sticky || f & 1 == 1) {
+		f += 1;
+	}
+	
+	let (f, e) = match biased < 1 {
+		//Rounding of the largest subnormal gives the smallest normal.
+		true => (f, (f >> F::MANTISSA_EXPLICIT_BITS) as i64),
+		false if f >> (F::MANTISSA_EXPLICIT_BITS + 1) != 0 => (f >> 1, biased + 1),
+		false => (f, biased),
+	};
+	if e >= F::INFINITE_POWER as i64 {
+		return F::INFINITY;
+	}
+	
+	F::from_biased(BiasedFp {
+		f: f & ((1 << F::MANTISSA_EXPLICIT_BITS) - 1),
+		e: e as i32,
+	})
+}
+
+pub fn atof_hex_parse<'a, F: AtofFloat, I: Iterator<Item=&'a u8>>(iter: &mut I, end: Option<u8>) -> AtofParse<F> {
+	let mut negative = false;
+	let mut mantissa = 0u64;
+	let mut sticky = false;
+	let mut binary_exponent = 0i64;
+	let mut any_digits = false;
+	let mut exponent = AtoiExp::default();
+	let mut state = State::Sign;
+	let mut pos = 0;
+	
+	for a in iter.by_ref() {
+		let a = *a;
+		match (state, a) {
+			_ if Some(a) == end => break,
+			(State::Sign, b'-') | (State::Sign, b'+') if pos == 0 => negative = a == b'-',
+			(State::Sign, b'0') => state = State::Zero,
+			(State::Zero, b'x') | (State::Zero, b'X') => state = State::Integer,
+			(State::Integer, _) | (State::Fraction, _) if a.is_ascii_hexdigit() => {
+				let digit = (a as char).to_digit(16).unwrap_or(0) as u64;
+				if mantissa >> 60 == 0 {
+					mantissa = mantissa << 4 | digit;
+					if state == State::Fraction {
+						binary_exponent -= 4;
+					}
+				}else {
+					sticky |= digit != 0;
+					if state == State::Integer {
+						binary_exponent += 4;
+					}
+				}
+				any_digits = true;
+			},
+			(State::Integer, b'.') => state = State::Fraction,
+			(State::Integer, b'p') | (State::Integer, b'P') | (State::Fraction, b'p') | (State::Fraction, b'P') => match any_digits {
+				true => state = State::Exponent,
+				false => return Err((AtoiErr::NoDigits, pos, true)),
+			},
+			(State::Exponent, b'-') | (State::Exponent, b'+') => {
+				exponent.negative = a == b'-';
+				state = State::ExponentSign;
+			},
+			(State::Exponent, b'0' ..= b'9') | (State::ExponentSign, b'0' ..= b'9') | (State::ExponentDigits, b'0' ..= b'9') => {
+				exponent.push_digit(a - b'0');
+				state = State::ExponentDigits;
+			},
+			_ => return Err((AtoiErr::ByteUnk(a), pos, true)),
+		}
+		pos += 1;
+	}
+	
+	match state {
+		State::Sign | State::Zero => return Err((AtoiErr::NoDigits, pos, false)),
+		State::Integer | State::Fraction if !any_digits => return Err((AtoiErr::NoDigits, pos, false)),
+		State::Integer | State::Fraction | State::Exponent | State::ExponentSign => return Err((AtoiErr::MissingExponent, pos, false)),
+		State::ExponentDigits => {},
+	}
+	
+	//Beyond the range the 64-bit mantissa is zero or infinity anyway.
+	let binary_exponent = exponent.add_to(binary_exponent).clamp(-0x10000, 0x10000);
+	let value = to_float::<F>(mantissa, sticky, binary_exponent);
+	Ok(match negative {
+		true => -value,
+		false => value,
+	})
+}
+
+
+#[cfg(test)]
+mod tests {
+	use Atof;
+	use AtoiErr;
+	
+	//The exact notation of f64.
+	fn hex(a: f64) -> String {
+		let bits = a.to_bits();
+		let sign = match bits >> 63 {
+			0 => "",
+			_ => "-",
+		};
+		let exponent = (bits >> 52 & 0x7FF) as i64;
+		let fraction = bits & ((1 << 52) - 1);
+		match exponent {
+			0 => format!("{}0x0.{:013x}p-1022", sign, fraction),
+			_ => format!("{}0x1.{:013x}p{}", sign, fraction, exponent - 1023),
+		}
+	}
+	
+	//f64 to f32 with a nonzero bit below the f64.
+	fn round_sticky(a: f64) -> f32 {
+		let r = a as f32;
+		if r as f64 == a || r.is_infinite() {
+			return r;
+		}
+		let other = match (r as f64).abs() < a.abs() {
+			true => f32::from_bits(r.to_bits() + 1),
+			false => f32::from_bits(r.to_bits() - 1),
+		};
+		match (r as f64 + other as f64) / 2.0 == a && other.abs() > r.abs() {
+			true => other,
+			false => r,
+		}
+	}
+	
+	#[test]
+	fn atof_hex() {
+		assert_eq!(f64::atof_hex(b"0x1.8p3"), Result::Ok(12.0));
+		assert_eq!(f32::atof_hex(b"-0x1.fffffep+127"), Result::Ok(f32::MIN));
+		assert_eq!(f32::atof_hex(b"0x1.ffffffp+127"), Result::Ok(f32::INFINITY));
+		assert_eq!(f64::atof_hex(b"0X.8P1"), Result::Ok(1.0));
+		assert_eq!(f64::atof_hex(b"0x10p-4"), Result::Ok(1.0));
+		assert_eq!(f64::atof_hex(b"0x0.0000000000001p-1022"), Result::Ok(f64::from_bits(1)));
+		assert_eq!(f64::atof_hex(b"0x1p-1075"), Result::Ok(0.0));
+		assert_eq!(f64::atof_hex(b"0x1.0000000000000000001p-1075"), Result::Ok(f64::from_bits(1)));
+		assert_eq!(f64::atof_hex(b"0x1.fffffffffffff8p-1023"), Result::Ok(f64::MIN_POSITIVE));
+		assert_eq!(f32::atof_hex(b"0x1.000001p0"), Result::Ok(1.0));
+		assert_eq!(f32::atof_hex(b"0x1.000003p0"), Result::Ok(1.0000002));
+		assert_eq!(f32::atof_hex(b"0x1.0000010000000000000000001p0"), Result::Ok(1.0000001));
+		assert_eq!(f64::atof_hex(b"-0x0p0").map(f64::is_sign_negative), Result::Ok(true));
+		assert_eq!(f64::atof_hex(b"0x1p99999999999"), Result::Ok(f64::INFINITY));
+		
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"0x1.8"), Result::Err((AtoiErr::MissingExponent, 5)));
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"0x1p"), Result::Err((AtoiErr::MissingExponent, 4)));
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"0x1p-"), Result::Err((AtoiErr::MissingExponent, 5)));
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"0x.p1"), Result::Err((AtoiErr::NoDigits, 3)));
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"-0"), Result::Err((AtoiErr::NoDigits, 2)));
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"1.8p3"), Result::Err((AtoiErr::ByteUnk(b'1'), 0)));
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"0x1g"), Result::Err((AtoiErr::ByteUnk(b'g'), 3)));
+		assert_eq!(f64::atof_hex_err::<(AtoiErr, usize)>(b"0x1p3.5"), Result::Err((AtoiErr::ByteUnk(b'.'), 5)));
+	}
+	
+	#[test]
+	fn atof_hex_long_exponent() {
+		let zeros = "0".repeat(1_000_000);
+		
+		assert_eq!(f64::atof_hex(format!("0x1{}p-4000000", zeros).as_bytes()), Result::Ok(1.0));
+		assert_eq!(f32::atof_hex(format!("0x1{}p-4000000", zeros).as_bytes()), Result::Ok(1.0));
+		assert_eq!(f64::atof_hex(format!("0x0.{}8p4000001", zeros).as_bytes()), Result::Ok(1.0));
+		assert_eq!(f64::atof_hex(format!("-0x3{}p-4000001", zeros).as_bytes()), Result::Ok(-1.5));
+		assert_eq!(f64::atof_hex(format!("0x0.{}1p999999999999999999999", zeros).as_bytes()), Result::Ok(f64::INFINITY));
+		assert_eq!(f64::atof_hex(format!("0x1{}p-999999999999999999999", zeros).as_bytes()), Result::Ok(0.0));
+	}
+	
+	#[test]
+	fn atof_hex_stop() {
+		assert_eq!(f64::atof_hex_stop(b"0x1p1 ", b' '), Result::Ok(2.0));
+		assert_eq!(f64::atof_hex_stop(b"0x1 p1", b' '), Result::Err(AtoiErr::MissingExponent));
+		
+		//The stop byte is checked before the sign.
+		assert_eq!(f64::atof_hex_stop(b"-0x1p0", b'-'), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(f64::atof_hex_stop(b"+0x1p0", b'+'), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(f64::atof_hex_stop(b"0x1p-1-", b'-'), Result::Err(AtoiErr::MissingExponent));
+		
+		let array = b"0x1p-1,0x1.8,-0x1p0";
+		let mut iter = array.iter();
+		assert_eq!(f32::atof_iter_hex_wait_stop(&mut iter, b','), Result::Ok(0.5));
+		assert_eq!(f32::atof_iter_hex_wait_stop(&mut iter, b','), Result::Err(AtoiErr::MissingExponent));
+		assert_eq!(f32::atof_iter_hex_wait_stop(&mut iter, b','), Result::Ok(-1.0));
+	}
+	
+	#[test]
+	fn atof_hex_random() {
+		let mut state = 0x9E37_79B9_7F4A_7C15u64;
+		for i in 0..200_000 {
+			state ^= state >> 12;
+			state ^= state << 25;
+			state ^= state >> 27;
+			let bits = state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+			//Every second number is in the range of f32 (with subnormals) and random bits below it.
+			let a = match i % 2 {
+				0 => f64::from_bits((f32::from_bits(bits as u32) as f64).to_bits() & !0x1FFF_FFFF | bits >> 35),
+				_ => f64::from_bits(bits),
+			};
+			if !a.is_finite() {
+				continue;
+			}
+			
+			let s = hex(a);
+			assert_eq!(f64::atof_hex(s.as_bytes()).map(f64::to_bits), Result::Ok(a.to_bits()), "{}", s);
+			assert_eq!(f32::atof_hex(s.as_bytes()).map(f32::to_bits), Result::Ok((a as f32).to_bits()), "{}", s);
+			
+			let s = s.replace('p', "0001p");
+			assert_eq!(f64::atof_hex(s.as_bytes()).map(f64::to_bits), Result::Ok(a.to_bits()), "{}", s);
+			assert_eq!(f32::atof_hex(s.as_bytes()).map(f32::to_bits), Result::Ok(round_sticky(a).to_bits()), "{}", s);
+		}
+	}
+}
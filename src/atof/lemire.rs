@@ -0,0 +1,96 @@
+//Eisel–Lemire: w * 10^q with the 128-bit approximation of 5^q, "Number Parsing at a Gigabyte per Second" (Lemire, 2021).
+
+use super::{AtofFloat, BiasedFp};
+use super::table::{POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE, LARGEST_POWER_OF_FIVE};
+
+//floor(q * log2(10)) + 63
+#[inline]
+fn power(q: i32) -> i32 {
+	(q.wrapping_mul(152_170 + 65536) >> 16) + 63
+}
+
+#[inline]
+fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
+	let r = a as u128 * b as u128;
+	(r as u64, (r >> 64) as u64)
+}
+
+//(low, high) 64 bits of w * 5^q with at least `precision` exact high bits.
+fn compute_product_approx(q: i64, w: u64, precision: u32) -> (u64, u64) {
+	debug_assert!((SMALLEST_POWER_OF_FIVE ..= LARGEST_POWER_OF_FIVE).contains(&q));
+	let mask = match precision < 64 {
+		true => u64::MAX >> precision,
+		false => u64::MAX,
+	};
+	
+	let (hi5, lo5) = POWER_OF_FIVE_128[(q - SMALLEST_POWER_OF_FIVE) as usize];
+	let (mut first_lo, mut first_hi) = full_multiplication(w, hi5);
+	if first_hi & mask == mask {
+		//The low 64 bits of 5^q are needed, exact when q < 55.
+		let (_, second_hi) = full_multiplication(w, lo5);
+		first_lo = first_lo.wrapping_add(second_hi);
+		if second_hi > first_lo {
+			first_hi += 1;
+		}
+	}
+	(first_lo, first_hi)
+}
+
+//The float of w * 10^q, `e < 0` if the approximation cannot be rounded correctly.
+pub fn compute_float<F: AtofFloat>(q: i64, mut w: u64) -> BiasedFp {
+	let zero = BiasedFp { f: 0, e: 0 };
+	let inf = BiasedFp { f: 0, e: F::INFINITE_POWER };
+	let error = BiasedFp { f: 0, e: -1 };
+	
+	if w == 0 || q < F::SMALLEST_POWER_OF_TEN as i64 {
+		return zero;
+	}else if q > F::LARGEST_POWER_OF_TEN as i64 {
+		return inf;
+	}
+	
+	let lz = w.leading_zeros();
+	w <<= lz;
+	let (lo, hi) = compute_product_approx(q, w, F::MANTISSA_EXPLICIT_BITS + 3);
+	if lo == u64::MAX && !(-27 ..= 55).contains(&q) {
+		//The product may be off by one near the halfway point.
+		return error;
+	}
+	
+	let upperbit = (hi >> 63) as i32;
+	let shift = upperbit + 64 - F::MANTISSA_EXPLICIT_BITS as i32 - 3;
+	let mut mantissa = hi >> shift;
+	let mut power2 = power(q as i32) + upperbit - lz as i32 - F::MINIMUM_EXPONENT;
+	if power2 <= 0 {
+		//Subnormal.
+		if -power2 + 1 >= 64 {
+			return zero;
+		}
+		mantissa >>= -power2 + 1;
+		mantissa += mantissa & 1;
+		mantissa >>= 1;
+		power2 = (mantissa >= 1 << F::MANTISSA_EXPLICIT_BITS) as i32;
+		return BiasedFp { f: mantissa, e: power2 };
+	}
+	
+	//Exactly halfway with an even result, round down.
+	if lo <= 1
+		&& (F::MIN_EXPONENT_ROUND_TO_EVEN as i64 ..= F::MAX_EXPONENT_ROUND_TO_EVEN as i64).contains(&q)
+		&& mantissa & 3 == 1
+		&& mantissa << shift == hi {
+		mantissa &= !1;
+	}
+	
+	mantissa += mantissa & 1;
+	mantissa >>= 1;
+	if mantissa >= 2 << F::MANTISSA_EXPLICIT_BITS {
+		//Rounding carried into the next bit.
+		mantissa = 1 << F::MANTISSA_EXPLICIT_BITS;
+		power2 += 1;
+	}
+	mantissa &= !(1 << F::MANTISSA_EXPLICIT_BITS);
+	if power2 >= F::INFINITE_POWER {
+		return inf;
+	}
+	
+	BiasedFp { f: mantissa, e: power2 }
+}
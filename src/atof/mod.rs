@@ -0,0 +1,672 @@
+use std::ops::{Div, Mul, Neg};
+use AtoiErr;
+use AtoiExp;
+use AtoiResult;
+use FromAtoiErr;
+use atoi_skip_stop;
+
+mod decimal;
+mod hex;
+mod lemire;
+mod table;
+
+use self::decimal::Decimal;
+
+///Parsing the byte sequence of the ascii characters into the correctly rounded `f32`/`f64`.
+///
+///The number is `[+-]digits[.digits][(e|E)[+-]digits]` or `inf`, `infinity`, `nan` in any case. As in `Atoi`, missing digits are zero.
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::Atof;
+///use cluatoi::AtoiErr;
+///
+///fn main() {
+///	assert_eq!(f64::atof(b"-1.5e3"), Ok(-1500.0));
+///	assert_eq!(f32::atof(b"0.1"), Ok(0.1));
+///	assert_eq!(f64::atof(b"-Infinity"), Ok(f64::NEG_INFINITY));
+///	assert!(f64::atof(b"NaN").unwrap().is_nan());
+///	assert_eq!(f64::atof(b"1.5x"), Err(AtoiErr::ByteUnk(b'x')));
+///
+///	let array = b"1.25;2e-3;";
+///	let mut array_iter = array.iter();
+///	assert_eq!(f64::atof_iter_stop(&mut array_iter, b';'), Ok(1.25));
+///	assert_eq!(f64::atof_iter_stop(&mut array_iter, b';'), Ok(0.002));
+///}
+///```
+pub trait Atof<T> {
+	///Array parsing.
+	#[inline]
+	fn atof(array: &[u8]) -> AtoiResult<T> {
+		Self::atof_iter(array.iter())
+	}
+	
+	///Array parsing and stopping on the 'X' character.
+	#[inline]
+	fn atof_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atof_iter_stop(array.iter(), stop)
+	}
+	
+	///An array analysis waiting for an "X" character even if an error occurred.
+	#[inline]
+	fn atof_wait_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atof_iter_wait_stop(array.iter(), stop)
+	}
+	
+	///Array parsing using an iterator.
+	#[inline]
+	fn atof_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T> {
+		Self::atof_iter_err(iter)
+	}
+	
+	///Array parsing using an iterator and stopping on the 'X' character.
+	#[inline]
+	fn atof_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		Self::atof_iter_stop_err(iter, end)
+	}
+	
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred.
+	///
+	///```rust
+	///use cluatoi::{Atof, AtoiErr};
+	///
+	///fn main() {
+	///	let array = b"1e1!1x5!-0.5";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(f32::atof_iter_wait_stop(&mut array_iter, b'!'), Ok(10.0));
+	///	assert_eq!(f32::atof_iter_wait_stop(&mut array_iter, b'!'), Err(AtoiErr::ByteUnk(b'x')));
+	///	assert_eq!(f32::atof_iter_wait_stop(&mut array_iter, b'!'), Ok(-0.5));
+	///}
+	///```
+	#[inline]
+	fn atof_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		Self::atof_iter_wait_stop_err(iter, end)
+	}
+	
+	///Array parsing with the error type of the caller, see `FromAtoiErr`.
+	#[inline]
+	fn atof_err<E: FromAtoiErr>(array: &[u8]) -> Result<T, E> {
+		Self::atof_iter_err(array.iter())
+	}
+	
+	///Array parsing and stopping on the 'X' character with the error type of the caller.
+	#[inline]
+	fn atof_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		Self::atof_iter_stop_err(array.iter(), stop)
+	}
+	
+	///An array analysis waiting for an "X" character even if an error occurred with the error type of the caller.
+	#[inline]
+	fn atof_wait_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		Self::atof_iter_wait_stop_err(array.iter(), stop)
+	}
+	
+	///Array parsing using an iterator with the error type of the caller.
+	fn atof_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, E>;
+	
+	///Array parsing using an iterator and stopping on the 'X' character with the error type of the caller.
+	fn atof_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E>;
+	
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred with the error type of the caller.
+	fn atof_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E>;
+	
+	///Hexadecimal array parsing, `[+-]0xhexdigits[.hexdigits]p[+-]digits` with the mandatory binary exponent.
+	///
+	///```rust
+	///use cluatoi::{Atof, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(f64::atof_hex(b"0x1.8p3"), Ok(12.0));
+	///	assert_eq!(f32::atof_hex(b"-0x1.fffffep+127"), Ok(f32::MIN));
+	///	assert_eq!(f32::atof_hex(b"0x1p-149"), Ok(f32::from_bits(1)));
+	///	assert_eq!(f64::atof_hex(b"0x1.8"), Err(AtoiErr::MissingExponent));
+	///}
+	///```
+	#[inline]
+	fn atof_hex(array: &[u8]) -> AtoiResult<T> {
+		Self::atof_iter_hex_err(array.iter())
+	}
+	
+	///Hexadecimal array parsing and stopping on the 'X' character.
+	#[inline]
+	fn atof_hex_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atof_iter_hex_stop_err(array.iter(), stop)
+	}
+	
+	///Hexadecimal array analysis waiting for an "X" character even if an error occurred.
+	#[inline]
+	fn atof_hex_wait_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atof_iter_hex_wait_stop_err(array.iter(), stop)
+	}
+	
+	///Hexadecimal array parsing using an iterator.
+	#[inline]
+	fn atof_iter_hex<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T> {
+		Self::atof_iter_hex_err(iter)
+	}
+	
+	///Hexadecimal array parsing using an iterator and stopping on the 'X' character.
+	#[inline]
+	fn atof_iter_hex_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		Self::atof_iter_hex_stop_err(iter, end)
+	}
+	
+	///Hexadecimal array analysis using an iterator and waiting for an "X" character even if an error occurred.
+	#[inline]
+	fn atof_iter_hex_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		Self::atof_iter_hex_wait_stop_err(iter, end)
+	}
+	
+	///Hexadecimal array parsing with the error type of the caller.
+	#[inline]
+	fn atof_hex_err<E: FromAtoiErr>(array: &[u8]) -> Result<T, E> {
+		Self::atof_iter_hex_err(array.iter())
+	}
+	
+	///Hexadecimal array parsing using an iterator with the error type of the caller.
+	fn atof_iter_hex_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, E>;
+	
+	///Hexadecimal array parsing using an iterator and stopping on the 'X' character with the error type of the caller.
+	fn atof_iter_hex_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E>;
+	
+	///Hexadecimal array analysis using an iterator and waiting for an "X" character even if an error occurred with the error type of the caller.
+	fn atof_iter_hex_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E>;
+}
+
+//Binary float, `e` is the biased exponent, `e < 0` is the error of Eisel–Lemire.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BiasedFp {
+	pub f: u64,
+	pub e: i32,
+}
+
+//Parameters of the binary format.
+pub trait AtofFloat: Copy + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self> {
+	const MANTISSA_EXPLICIT_BITS: u32;
+	const MINIMUM_EXPONENT: i32;
+	const INFINITE_POWER: i32;
+	const SMALLEST_POWER_OF_TEN: i32;
+	const LARGEST_POWER_OF_TEN: i32;
+	const MIN_EXPONENT_ROUND_TO_EVEN: i32;
+	const MAX_EXPONENT_ROUND_TO_EVEN: i32;
+	const MIN_EXPONENT_FAST_PATH: i64;
+	const MAX_EXPONENT_FAST_PATH: i64;
+	const MAX_EXPONENT_DISGUISED_FAST_PATH: i64;
+	const MAX_MANTISSA_FAST_PATH: u64;
+	
+	const ZERO: Self;
+	const INFINITY: Self;
+	const NAN: Self;
+	
+	fn from_u64(a: u64) -> Self;
+	fn pow10_fast_path(exponent: usize) -> Self;
+	fn from_biased(fp: BiasedFp) -> Self;
+}
+
+impl AtofFloat for f64 {
+	const MANTISSA_EXPLICIT_BITS: u32 = 52;
+	const MINIMUM_EXPONENT: i32 = -1023;
+	const INFINITE_POWER: i32 = 0x7FF;
+	const SMALLEST_POWER_OF_TEN: i32 = -342;
+	const LARGEST_POWER_OF_TEN: i32 = 308;
+	const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -4;
+	const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 23;
+	const MIN_EXPONENT_FAST_PATH: i64 = -22;
+	const MAX_EXPONENT_FAST_PATH: i64 = 22;
+	const MAX_EXPONENT_DISGUISED_FAST_PATH: i64 = 22 + 15;
+	const MAX_MANTISSA_FAST_PATH: u64 = 2 << 52;
+	
+	const ZERO: Self = 0.0;
+	const INFINITY: Self = f64::INFINITY;
+	const NAN: Self = f64::NAN;
+	
+	#[inline]
+	fn from_u64(a: u64) -> Self {
+		a as f64
+	}
+	
+	#[inline]
+	fn pow10_fast_path(exponent: usize) -> Self {
+		const TABLE: [f64; 23] = [
+			1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11,
+			1e12, 1e13, 1e14, 1e15, 1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+		];
+		TABLE[exponent]
+	}
+	
+	#[inline]
+	fn from_biased(fp: BiasedFp) -> Self {
+		f64::from_bits(fp.f | (fp.e as u64) << Self::MANTISSA_EXPLICIT_BITS)
+	}
+}
+
+impl AtofFloat for f32 {
+	const MANTISSA_EXPLICIT_BITS: u32 = 23;
+	const MINIMUM_EXPONENT: i32 = -127;
+	const INFINITE_POWER: i32 = 0xFF;
+	const SMALLEST_POWER_OF_TEN: i32 = -65;
+	const LARGEST_POWER_OF_TEN: i32 = 38;
+	const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -17;
+	const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 10;
+	const MIN_EXPONENT_FAST_PATH: i64 = -10;
+	const MAX_EXPONENT_FAST_PATH: i64 = 10;
+	const MAX_EXPONENT_DISGUISED_FAST_PATH: i64 = 10 + 7;
+	const MAX_MANTISSA_FAST_PATH: u64 = 2 << 23;
+	
+	const ZERO: Self = 0.0;
+	const INFINITY: Self = f32::INFINITY;
+	const NAN: Self = f32::NAN;
+	
+	#[inline]
+	fn from_u64(a: u64) -> Self {
+		a as f32
+	}
+	
+	#[inline]
+	fn pow10_fast_path(exponent: usize) -> Self {
+		const TABLE: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+		TABLE[exponent]
+	}
+	
+	#[inline]
+	fn from_biased(fp: BiasedFp) -> Self {
+		f32::from_bits((fp.f | (fp.e as u64) << Self::MANTISSA_EXPLICIT_BITS) as u32)
+	}
+}
+
+//Significant digits kept in the u64 mantissa.
+const MANTISSA_DIGITS: usize = 19;
+
+//The decimal number: mantissa * 10^exponent, digits after the 19th are only needed by the slow path.
+struct AtofNum {
+	negative: bool,
+	mantissa: u64,
+	kept: usize,
+	exponent: i64,
+	//Nonzero digits after the mantissa.
+	truncated: bool,
+	//Nonzero digits after Decimal::MAX_DIGITS.
+	dropped: bool,
+	digits: Vec<u8>,
+}
+
+impl AtofNum {
+	fn push_digit(&mut self, digit: u8, fraction: bool) {
+		if self.kept == 0 && digit == 0 {
+			//Leading zero.
+			if fraction {
+				self.exponent -= 1;
+			}
+		}else if self.kept < MANTISSA_DIGITS {
+			self.mantissa = self.mantissa * 10 + digit as u64;
+			self.kept += 1;
+			if fraction {
+				self.exponent -= 1;
+			}
+		}else {
+			if self.digits.is_empty() {
+				self.digits.extend(mantissa_digits(self.mantissa, self.kept));
+			}
+			match self.digits.len() < Decimal::MAX_DIGITS {
+				true => self.digits.push(digit),
+				false => self.dropped |= digit != 0,
+			}
+			self.truncated |= digit != 0;
+			if !fraction {
+				self.exponent += 1;
+			}
+		}
+	}
+	
+	fn to_float<F: AtofFloat>(&self) -> F {
+		let value = match self.mantissa {
+			0 => F::ZERO,
+			_ => match self.fast_path() {
+				Some(value) => value,
+				None => F::from_biased(self.lemire::<F>()),
+			},
+		};
+		
+		match self.negative {
+			true => -value,
+			false => value,
+		}
+	}
+	
+	//Clinger, the mantissa and the power of ten are exact floats.
+	fn fast_path<F: AtofFloat>(&self) -> Option<F> {
+		const INT_POW10: [u64; 16] = [
+			1, 10, 100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000, 100_000_000, 1_000_000_000,
+			10_000_000_000, 100_000_000_000, 1_000_000_000_000, 10_000_000_000_000, 100_000_000_000_000,
+			1_000_000_000_000_000,
+		];
+		
+		if self.truncated || self.mantissa > F::MAX_MANTISSA_FAST_PATH
+			|| !(F::MIN_EXPONENT_FAST_PATH ..= F::MAX_EXPONENT_DISGUISED_FAST_PATH).contains(&self.exponent) {
+			return None;
+		}
+		
+		match self.exponent {
+			e if e < 0 => Some(F::from_u64(self.mantissa) / F::pow10_fast_path(-e as usize)),
+			e if e <= F::MAX_EXPONENT_FAST_PATH => Some(F::from_u64(self.mantissa) * F::pow10_fast_path(e as usize)),
+			e => {
+				//Disguised: the part of the power of ten is moved into the mantissa.
+				let mantissa = self.mantissa.checked_mul(INT_POW10[(e - F::MAX_EXPONENT_FAST_PATH) as usize])?;
+				match mantissa <= F::MAX_MANTISSA_FAST_PATH {
+					true => Some(F::from_u64(mantissa) * F::pow10_fast_path(F::MAX_EXPONENT_FAST_PATH as usize)),
+					false => None,
+				}
+			},
+		}
+	}
+	
+	fn lemire<F: AtofFloat>(&self) -> BiasedFp {
+		let mut fp = lemire::compute_float::<F>(self.exponent, self.mantissa);
+		if self.truncated && fp.e >= 0 && fp != lemire::compute_float::<F>(self.exponent, self.mantissa + 1) {
+			//The dropped digits can change the rounding.
+			fp.e = -1;
+		}
+		if fp.e >= 0 {
+			return fp;
+		}
+		
+		let mut d = Decimal::new();
+		match self.digits.is_empty() {
+			true => mantissa_digits(self.mantissa, self.kept).for_each(|a| d.push_digit(a)),
+			false => self.digits.iter().for_each(|a| d.push_digit(*a)),
+		}
+		d.truncated |= self.dropped;
+		d.decimal_point = self.exponent.saturating_add(self.kept as i64).clamp(-100_000, 100_000) as i32;
+		d.trim();
+		d.into_float::<F>()
+	}
+}
+
+//The decimal digits of the mantissa from the most significant one.
+fn mantissa_digits(mantissa: u64, len: usize) -> impl Iterator<Item=u8> {
+	(0..len).rev().map(move |i| (mantissa / 10u64.pow(i as u32) % 10) as u8)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+	Integer,
+	Fraction,
+	ExponentSign,
+	Exponent,
+}
+
+//The error, its position and whether the stop byte is still ahead.
+type AtofParse<F> = Result<F, (AtoiErr, usize, bool)>;
+
+//inf, infinity, nan after the first letter.
+fn atof_special<'a, F: AtofFloat, I: Iterator<Item=&'a u8>>(iter: &mut I, first: u8, end: Option<u8>, pos: &mut usize) -> AtofParse<F> {
+	let (word, value): (&[u8], F) = match first | 0x20 {
+		b'i' => (b"infinity", F::INFINITY),
+		_ => (b"nan", F::NAN),
+	};
+	
+	let mut len = 1;
+	loop {
+		*pos += 1;
+		match iter.next() {
+			Some(a) if Some(*a) == end => break,
+			Some(a) if word.get(len) == Some(&(*a | 0x20)) => len += 1,
+			Some(a) => return Err((AtoiErr::ByteUnk(*a), *pos, true)),
+			None => break,
+		}
+	}
+	
+	match len == 3 || len == word.len() {
+		true => Ok(value),
+		false => Err((AtoiErr::NoDigits, *pos, false)),
+	}
+}
+
+fn atof_parse<'a, F: AtofFloat, I: Iterator<Item=&'a u8>>(iter: &mut I, end: Option<u8>) -> AtofParse<F> {
+	let mut num = AtofNum {
+		negative: false,
+		mantissa: 0,
+		kept: 0,
+		exponent: 0,
+		truncated: false,
+		dropped: false,
+		digits: Vec::new(),
+	};
+	let mut exponent = AtoiExp::default();
+	let mut any_digits = false;
+	let mut state = State::Integer;
+	let mut pos = 0;
+	
+	while let Some(a) = iter.next() {
+		let a = *a;
+		match (state, a) {
+			(State::Integer, b'-') | (State::Integer, b'+') if pos == 0 => num.negative = a == b'-',
+			_ if Some(a) == end => break,
+			(State::Integer, b'0' ..= b'9') | (State::Fraction, b'0' ..= b'9') => {
+				num.push_digit(a - b'0', state == State::Fraction);
+				any_digits = true;
+			},
+			(State::Integer, b'.') => state = State::Fraction,
+			(State::Integer, b'e') | (State::Integer, b'E') | (State::Fraction, b'e') | (State::Fraction, b'E') => state = State::ExponentSign,
+			(State::ExponentSign, b'-') | (State::ExponentSign, b'+') => {
+				exponent.negative = a == b'-';
+				state = State::Exponent;
+			},
+			(State::ExponentSign, b'0' ..= b'9') | (State::Exponent, b'0' ..= b'9') => {
+				exponent.push_digit(a - b'0');
+				state = State::Exponent;
+			},
+			(State::Integer, _) if !any_digits && (a | 0x20 == b'i' || a | 0x20 == b'n') => {
+				let value = atof_special::<F, I>(iter, a, end, &mut pos)?;
+				return Ok(match num.negative {
+					true => -value,
+					false => value,
+				});
+			},
+			_ => return Err((AtoiErr::ByteUnk(a), pos, true)),
+		}
+		pos += 1;
+	}
+	
+	num.exponent = exponent.add_to(num.exponent);
+	Ok(num.to_float())
+}
+
+//The erroneous byte and the wait of the stop byte as in Atoi.
+fn atof_err<'a, F, E, I>(mut iter: I, end: Option<u8>, wait: bool, parse: fn(&mut I, Option<u8>) -> AtofParse<F>) -> Result<F, E>
+	where F: AtofFloat, E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	parse(&mut iter, end).map_err(|(err, pos, ahead)| {
+		atoi_skip_stop(iter, end, wait && ahead);
+		E::from_atoi_err(err, pos)
+	})
+}
+
+macro_rules! atof_build {
+	($($t:ty),+) => {
+		$(
+		impl Atof<$t> for $t {
+			#[inline]
+			fn atof_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<$t, E> {
+				atof_err(iter, None, false, atof_parse)
+			}
+			
+			#[inline]
+			fn atof_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<$t, E> {
+				atof_err(iter, Some(end), false, atof_parse)
+			}
+			
+			#[inline]
+			fn atof_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<$t, E> {
+				atof_err(iter, Some(end), true, atof_parse)
+			}
+			
+			#[inline]
+			fn atof_iter_hex_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<$t, E> {
+				atof_err(iter, None, false, hex::atof_hex_parse)
+			}
+			
+			#[inline]
+			fn atof_iter_hex_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<$t, E> {
+				atof_err(iter, Some(end), false, hex::atof_hex_parse)
+			}
+			
+			#[inline]
+			fn atof_iter_hex_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<$t, E> {
+				atof_err(iter, Some(end), true, hex::atof_hex_parse)
+			}
+		}
+		)+
+	};
+}
+
+atof_build!(f32, f64);
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	//xorshift64*
+	struct Rand(u64);
+	
+	impl Rand {
+		fn next(&mut self) -> u64 {
+			self.0 ^= self.0 >> 12;
+			self.0 ^= self.0 << 25;
+			self.0 ^= self.0 >> 27;
+			self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+		}
+		
+		fn below(&mut self, n: u64) -> u64 {
+			self.next() % n
+		}
+	}
+	
+	fn check(s: &str) {
+		let std64 = s.parse::<f64>().unwrap();
+		let std32 = s.parse::<f32>().unwrap();
+		let our64 = f64::atof(s.as_bytes()).unwrap();
+		let our32 = f32::atof(s.as_bytes()).unwrap();
+		assert!(std64.to_bits() == our64.to_bits() || std64.is_nan() && our64.is_nan(), "f64 {}: {} != {}", s, std64, our64);
+		assert!(std32.to_bits() == our32.to_bits() || std32.is_nan() && our32.is_nan(), "f32 {}: {} != {}", s, std32, our32);
+	}
+	
+	#[test]
+	fn atof_special() {
+		for s in &["0", "-0", "0.0", "1", "-1", "1.", ".5", "0.1", "1e10", "1E-10", "+1.5e+3", "123456789012345678901234567890",
+			"inf", "-inf", "+INF", "Infinity", "-infinity", "nan", "NaN", "-nan",
+			"1e308", "1.7976931348623157e308", "1.7976931348623159e308", "1e309", "2e-308", "4.9406564584124654e-324",
+			"2.4703282292062327e-324", "2.4703282292062328e-324", "1e-400", "3.4028235e38", "3.4028236e38", "1e-45", "7e-46",
+			"9007199254740993", "9007199254740992.000000000000000000000000000001", "1e23", "8.589973e9", "0.000000000000000000000000000000000001",
+			"2.2250738585072011e-308", "2.2250738585072012e-308", "1.00000005960464477539062499", "1.000000059604644775390625",
+			"1.00000005960464477539062501", "1e99999999999", "1e-99999999999", "0e99999999999"] {
+			check(s);
+		}
+		
+		assert_eq!(f64::atof(b""), Result::Ok(0.0));
+		assert_eq!(f64::atof(b"-").map(f64::is_sign_negative), Result::Ok(true));
+		assert_eq!(f64::atof(b"1.2.3"), Result::Err(AtoiErr::ByteUnk(b'.')));
+		assert_eq!(f64::atof(b"1e5e"), Result::Err(AtoiErr::ByteUnk(b'e')));
+		assert_eq!(f64::atof(b"1e+-5"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(f64::atof(b"infx"), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(f64::atof(b"infin"), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(f64::atof(b"1inf"), Result::Err(AtoiErr::ByteUnk(b'i')));
+		assert_eq!(f64::atof_err::<(AtoiErr, usize)>(b"-12.5z"), Result::Err((AtoiErr::ByteUnk(b'z'), 5)));
+		assert_eq!(f64::atof_err::<(AtoiErr, usize)>(b"-nax"), Result::Err((AtoiErr::ByteUnk(b'x'), 3)));
+		assert_eq!(f64::atof_stop(b"inf;", b';'), Result::Ok(f64::INFINITY));
+		assert_eq!(f64::atof_stop(b"-5e;", b';'), Result::Ok(-5.0));
+	}
+	
+	#[test]
+	fn atof_long_exponent() {
+		let zeros = "0".repeat(1_000_000);
+		
+		//The digits and the exponent cancel out, the exponent alone is out of the range.
+		assert_eq!(f64::atof(format!("1{}e-1000000", zeros).as_bytes()), Result::Ok(1.0));
+		assert_eq!(f32::atof(format!("1{}e-1000000", zeros).as_bytes()), Result::Ok(1.0));
+		assert_eq!(f64::atof(format!("0.{}15e1000001", zeros).as_bytes()), Result::Ok(1.5));
+		assert_eq!(f64::atof(format!("-25{}e-1000001", zeros).as_bytes()), Result::Ok(-2.5));
+		assert_eq!(f64::atof(format!("1{}e-1000309", zeros).as_bytes()), Result::Ok(1e-309));
+		assert_eq!(f64::atof(format!("0.{}1e999999999999999999999", zeros).as_bytes()), Result::Ok(f64::INFINITY));
+		assert_eq!(f64::atof(format!("1{}e-999999999999999999999", zeros).as_bytes()), Result::Ok(0.0));
+	}
+	
+	#[test]
+	fn atof_stop() {
+		let array = b"1.5e1,nan,x,-2";
+		let mut iter = array.iter();
+		assert_eq!(f64::atof_iter_wait_stop(&mut iter, b','), Result::Ok(15.0));
+		assert_eq!(f64::atof_iter_wait_stop(&mut iter, b',').map(f64::is_nan), Result::Ok(true));
+		assert_eq!(f64::atof_iter_wait_stop(&mut iter, b','), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(f64::atof_iter_wait_stop(&mut iter, b','), Result::Ok(-2.0));
+		
+		let array = b"infin,1";
+		let mut iter = array.iter();
+		assert_eq!(f64::atof_iter_wait_stop(&mut iter, b','), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(f64::atof_iter_wait_stop(&mut iter, b','), Result::Ok(1.0));
+	}
+	
+	#[test]
+	fn atof_corpus() {
+		let mut rand = Rand(0x9E37_79B9_7F4A_7C15);
+		
+		for _ in 0..100_000 {
+			//Shortest and long representations of random floats.
+			let a = f64::from_bits(rand.next());
+			if a.is_finite() {
+				check(&format!("{:e}", a));
+				check(&format!("{:.25e}", a));
+			}
+			let b = f32::from_bits(rand.next() as u32);
+			if b.is_finite() {
+				check(&format!("{}", b));
+			}
+			
+			//Random digits and exponents.
+			let mut s = String::new();
+			for _ in 0..rand.below(40) + 1 {
+				s.push((b'0' + rand.below(10) as u8) as char);
+			}
+			if rand.below(2) == 0 {
+				let dot = rand.below(s.len() as u64 + 1) as usize;
+				s.insert(dot, '.');
+			}
+			s.push_str(&format!("e{}", rand.below(700) as i64 - 350));
+			check(&s);
+		}
+	}
+	
+	#[test]
+	fn atof_halfway() {
+		let mut rand = Rand(0x2545_F491_4F6C_DD1D);
+		
+		for _ in 0..20_000 {
+			//The midpoint of two neighbouring f32 is exact in f64, its exact decimal notation
+			//and the notations slightly above and below it.
+			let a = f32::from_bits(rand.next() as u32 & 0x7F7F_FFFF);
+			let mid = (a as f64 + f32::from_bits(a.to_bits() + 1) as f64) / 2.0;
+			let exact = format!("{:.120e}", mid);
+			check(&exact);
+			
+			let (mantissa, exponent) = exact.split_at(exact.find('e').unwrap());
+			let digits = mantissa.trim_end_matches('0');
+			check(&format!("{}1{}", digits, exponent));
+			if let Some(i) = digits.rfind(|c: char| c != '0' && c != '.') {
+				let below = &digits[..i];
+				let last = digits.as_bytes()[i] - 1;
+				check(&format!("{}{}{}{}", below, last as char, "9".repeat(30), exponent));
+			}
+		}
+		
+		//The midpoint of 1 and the next f64 padded with zeros up to and past Decimal::MAX_DIGITS,
+		//it rounds up only with a nonzero digit after the zeros.
+		for zeros in &[0, 713, 714, 1000] {
+			let padded = format!("1.00000000000000011102230246251565404236316680908203125{}", "0".repeat(*zeros));
+			check(&padded);
+			check(&format!("{}1", padded));
+		}
+		assert_eq!(f64::atof(format!("1.00000000000000011102230246251565404236316680908203125{}", "0".repeat(714)).as_bytes()), Result::Ok(1.0));
+	}
+}
@@ -0,0 +1,417 @@
+use std::convert::TryFrom;
+use Atoi;
+use AtoiErr;
+use AtoiOverflow;
+use AtoiPolicy;
+use AtoiResult;
+use FromAtoiErr;
+use atoi_skip_stop;
+
+//Decimal digits in one u64 chunk, 10^19 < 2^64.
+const CHUNK_DIGITS: usize = 19;
+const CHUNK_POW: [u64; CHUNK_DIGITS + 1] = [
+	1, 10, 100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000, 100_000_000, 1_000_000_000,
+	10_000_000_000, 100_000_000_000, 1_000_000_000_000, 10_000_000_000_000, 100_000_000_000_000,
+	1_000_000_000_000_000, 10_000_000_000_000_000, 100_000_000_000_000_000, 1_000_000_000_000_000_000,
+	10_000_000_000_000_000_000,
+];
+
+//Up to this length the digits are accumulated chunk by chunk.
+const SIMPLE_DIGITS: usize = CHUNK_DIGITS * 32;
+//Up to this length (in limbs) the schoolbook multiplication is used.
+const KARATSUBA_LIMBS: usize = 32;
+
+///Integer of arbitrary precision, the result of parsing numbers which do not fit into `u128`/`i128`.
+///
+///The magnitude is stored as little-endian `u64` limbs without high zero limbs, zero has no limbs and is never negative.
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::Atoi;
+///use cluatoi::BigInt;
+///
+///fn main() {
+///	let num = BigInt::atoi(b"-340282366920938463463374607431768211456").unwrap(); //-2^128
+///	assert_eq!(num.is_negative(), true);
+///	assert_eq!(num.limbs(), &[0, 0, 1]);
+///
+///	let num = BigInt::atoi_stop(b"18446744073709551616;", b';').unwrap(); //2^64
+///	assert_eq!(num.limbs(), &[0, 1]);
+///}
+///```
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Default)]
+pub struct BigInt {
+	negative: bool,
+	limbs: Vec<u64>,
+}
+
+impl BigInt {
+	///Number from the sign and little-endian limbs of the magnitude.
+	pub fn from_limbs(negative: bool, mut limbs: Vec<u64>) -> Self {
+		normalize(&mut limbs);
+		let negative = negative && !limbs.is_empty();
+		
+		BigInt {
+			negative,
+			limbs,
+		}
+	}
+	
+	///Little-endian limbs of the magnitude.
+	#[inline]
+	pub fn limbs(&self) -> &[u64] {
+		&self.limbs
+	}
+	
+	///Sign and little-endian limbs of the magnitude.
+	#[inline]
+	pub fn into_parts(self) -> (bool, Vec<u64>) {
+		(self.negative, self.limbs)
+	}
+	
+	#[inline]
+	pub fn is_negative(&self) -> bool {
+		self.negative
+	}
+	
+	#[inline]
+	pub fn is_zero(&self) -> bool {
+		self.limbs.is_empty()
+	}
+	
+	///The number if it fits into `u128`.
+	pub fn to_u128(&self) -> Option<u128> {
+		match (self.negative, self.limbs.len()) {
+			(_, 0) => Some(0),
+			(false, 1) => Some(self.limbs[0] as u128),
+			(false, 2) => Some((self.limbs[1] as u128) << 64 | self.limbs[0] as u128),
+			_ => None,
+		}
+	}
+	
+	///The number if it fits into `i128`.
+	pub fn to_i128(&self) -> Option<i128> {
+		let magnitude = BigInt::from_limbs(false, self.limbs.clone()).to_u128()?;
+		match self.negative {
+			true if magnitude == i128::MIN.unsigned_abs() => Some(i128::MIN),
+			true => i128::try_from(magnitude).ok().map(|a| -a),
+			false => i128::try_from(magnitude).ok(),
+		}
+	}
+}
+
+impl From<u128> for BigInt {
+	fn from(a: u128) -> Self {
+		BigInt::from_limbs(false, vec![a as u64, (a >> 64) as u64])
+	}
+}
+
+impl From<i128> for BigInt {
+	fn from(a: i128) -> Self {
+		let magnitude = a.unsigned_abs();
+		BigInt::from_limbs(a < 0, vec![magnitude as u64, (magnitude >> 64) as u64])
+	}
+}
+
+impl Atoi<BigInt> for BigInt {
+	#[inline]
+	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<BigInt> {
+		Self::atoi_iter_err(iter)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<BigInt> {
+		Self::atoi_iter_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<BigInt> {
+		Self::atoi_iter_wait_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<BigInt, E> {
+		big_int_err(iter, None, false)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<BigInt, E> {
+		big_int_err(iter, Some(end), false)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<BigInt, E> {
+		big_int_err(iter, Some(end), true)
+	}
+}
+
+//Never overflows, the flag is always `false`.
+impl AtoiPolicy<BigInt> for BigInt {
+	#[inline]
+	fn atoi_iter_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, _policy: AtoiOverflow) -> AtoiResult<(BigInt, bool)> {
+		big_int_err(iter, None, false).map(|a| (a, false))
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, _policy: AtoiOverflow) -> AtoiResult<(BigInt, bool)> {
+		big_int_err(iter, Some(end), false).map(|a| (a, false))
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, _policy: AtoiOverflow) -> AtoiResult<(BigInt, bool)> {
+		big_int_err(iter, Some(end), true).map(|a| (a, false))
+	}
+	
+	#[inline]
+	fn atoi_iter_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, _policy: AtoiOverflow) -> Result<(BigInt, bool), E> {
+		big_int_err(iter, None, false).map(|a| (a, false))
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, _policy: AtoiOverflow) -> Result<(BigInt, bool), E> {
+		big_int_err(iter, Some(end), false).map(|a| (a, false))
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, _policy: AtoiOverflow) -> Result<(BigInt, bool), E> {
+		big_int_err(iter, Some(end), true).map(|a| (a, false))
+	}
+}
+
+//The same byte rules as the signed primitives, the sign is taken before the stop byte.
+fn big_int_err<'a, E, I>(mut iter: I, end: Option<u8>, wait: bool) -> Result<BigInt, E>
+	where E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let mut negative = false;
+	let mut digits = Vec::new();
+	let mut pos = 0;
+	while let Some(a) = iter.next() {
+		let a = *a;
+		match a {
+			b'-' | b'+' if pos == 0 => negative = a == b'-',
+			_ if Some(a) == end => break,
+			_ if a.is_ascii_digit() => digits.push(a - b'0'),
+			_ => {
+				atoi_skip_stop(iter, end, wait);
+				return Err(E::from_atoi_err(AtoiErr::ByteUnk(a), pos));
+			},
+		}
+		pos += 1;
+	}
+	
+	let mut pows = Vec::new();
+	Ok(BigInt::from_limbs(negative, digits_to_limbs(&digits, &mut pows)))
+}
+
+//Long sequences are split in two, high * 10^(19 * 2^j) + low, which is subquadratic with the Karatsuba multiplication.
+fn digits_to_limbs(digits: &[u8], pows: &mut Vec<Vec<u64>>) -> Vec<u64> {
+	if digits.len() <= SIMPLE_DIGITS {
+		let mut limbs = Vec::new();
+		let head = digits.len() % CHUNK_DIGITS;
+		let (head, tail) = digits.split_at(head);
+		for chunk in Some(head).into_iter().chain(tail.chunks(CHUNK_DIGITS)) {
+			let value = chunk.iter().fold(0, |acc, a| acc * 10 + *a as u64);
+			mul_small_add(&mut limbs, CHUNK_POW[chunk.len()], value);
+		}
+		return limbs;
+	}
+	
+	let mut level = 0;
+	while CHUNK_DIGITS << (level + 1) < digits.len() {
+		level += 1;
+	}
+	let (high, low) = digits.split_at(digits.len() - (CHUNK_DIGITS << level));
+	let high = digits_to_limbs(high, pows);
+	let low = digits_to_limbs(low, pows);
+	
+	let mut result = mul(&high, pow10(pows, level));
+	add_at(&mut result, &low, 0);
+	result
+}
+
+//10^(19 * 2^level), squared from the previous level.
+fn pow10(pows: &mut Vec<Vec<u64>>, level: usize) -> &[u64] {
+	if pows.is_empty() {
+		pows.push(vec![CHUNK_POW[CHUNK_DIGITS]]);
+	}
+	while pows.len() <= level {
+		let next = {
+			let last = &pows[pows.len() - 1];
+			mul(last, last)
+		};
+		pows.push(next);
+	}
+	&pows[level]
+}
+
+fn normalize(limbs: &mut Vec<u64>) {
+	while limbs.last() == Some(&0) {
+		limbs.pop();
+	}
+}
+
+fn trim(limbs: &[u64]) -> &[u64] {
+	let len = limbs.iter().rposition(|a| *a != 0).map_or(0, |a| a + 1);
+	&limbs[..len]
+}
+
+//limbs = limbs * mul + add
+fn mul_small_add(limbs: &mut Vec<u64>, mul: u64, add: u64) {
+	let mut carry = add as u128;
+	for limb in limbs.iter_mut() {
+		let value = *limb as u128 * mul as u128 + carry;
+		*limb = value as u64;
+		carry = value >> 64;
+	}
+	if carry != 0 {
+		limbs.push(carry as u64);
+	}
+}
+
+//acc += b * 2^(64 * shift)
+fn add_at(acc: &mut Vec<u64>, b: &[u64], shift: usize) {
+	if acc.len() < shift + b.len() {
+		acc.resize(shift + b.len(), 0);
+	}
+	let mut carry = false;
+	let mut i = shift;
+	for b in b {
+		let (sum, c1) = acc[i].overflowing_add(*b);
+		let (sum, c2) = sum.overflowing_add(carry as u64);
+		acc[i] = sum;
+		carry = c1 || c2;
+		i += 1;
+	}
+	while carry {
+		if i == acc.len() {
+			acc.push(1);
+			break;
+		}
+		let (sum, c) = acc[i].overflowing_add(1);
+		acc[i] = sum;
+		carry = c;
+		i += 1;
+	}
+}
+
+//acc -= b, acc >= b
+fn sub_assign(acc: &mut Vec<u64>, b: &[u64]) {
+	let mut borrow = false;
+	for (i, limb) in acc.iter_mut().enumerate() {
+		if i >= b.len() && !borrow {
+			break;
+		}
+		let (diff, c1) = limb.overflowing_sub(b.get(i).cloned().unwrap_or(0));
+		let (diff, c2) = diff.overflowing_sub(borrow as u64);
+		*limb = diff;
+		borrow = c1 || c2;
+	}
+	normalize(acc);
+}
+
+fn mul_basic(a: &[u64], b: &[u64]) -> Vec<u64> {
+	let mut result = vec![0; a.len() + b.len()];
+	for (i, a) in a.iter().enumerate() {
+		let mut carry = 0;
+		for (j, b) in b.iter().enumerate() {
+			let value = *a as u128 * *b as u128 + result[i + j] as u128 + carry;
+			result[i + j] = value as u64;
+			carry = value >> 64;
+		}
+		result[i + b.len()] = carry as u64;
+	}
+	normalize(&mut result);
+	result
+}
+
+//Karatsuba, a * b = z2 * B^2h + (z1 - z2 - z0) * B^h + z0
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+	if a.len().min(b.len()) < KARATSUBA_LIMBS {
+		return mul_basic(a, b);
+	}
+	
+	let half = a.len().max(b.len()) / 2;
+	let split = |a: &[u64]| -> (Vec<u64>, Vec<u64>) {
+		match a.len() > half {
+			true => (trim(&a[..half]).to_vec(), a[half..].to_vec()),
+			false => (a.to_vec(), Vec::new()),
+		}
+	};
+	let (a0, a1) = split(a);
+	let (b0, b1) = split(b);
+	
+	let z0 = mul(&a0, &b0);
+	let z2 = mul(&a1, &b1);
+	
+	let mut sum_a = a0;
+	add_at(&mut sum_a, &a1, 0);
+	let mut sum_b = b0;
+	add_at(&mut sum_b, &b1, 0);
+	let mut z1 = mul(&sum_a, &sum_b);
+	sub_assign(&mut z1, &z0);
+	sub_assign(&mut z1, &z2);
+	
+	let mut result = z0;
+	add_at(&mut result, &z1, half);
+	add_at(&mut result, &z2, half * 2);
+	normalize(&mut result);
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	//Digit by digit, quadratic.
+	fn naive(digits: &[u8]) -> Vec<u64> {
+		let mut limbs = Vec::new();
+		for a in digits {
+			mul_small_add(&mut limbs, 10, (*a - b'0') as u64);
+		}
+		limbs
+	}
+	
+	#[test]
+	fn big_int() {
+		assert_eq!(BigInt::atoi(b"0"), Result::Ok(BigInt::default()));
+		assert_eq!(BigInt::atoi(b"-0"), Result::Ok(BigInt::default()));
+		assert_eq!(BigInt::atoi(b"-170141183460469231731687303715884105728").map(|a| a.to_i128()), Result::Ok(Some(i128::MIN)));
+		assert_eq!(BigInt::atoi(b"340282366920938463463374607431768211455").map(|a| a.to_u128()), Result::Ok(Some(u128::MAX)));
+		assert_eq!(BigInt::atoi(b"340282366920938463463374607431768211456").map(|a| a.to_u128()), Result::Ok(None));
+		assert_eq!(BigInt::atoi(b"+00012345678901234567890"), Result::Ok(BigInt::from(12345678901234567890u128)));
+		
+		assert_eq!(BigInt::atoi(b"12-3"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(BigInt::atoi_err::<(AtoiErr, usize)>(b"-1x"), Result::Err((AtoiErr::ByteUnk(b'x'), 2)));
+		assert_eq!(BigInt::atoi_policy(b"-1", AtoiOverflow::Error), Result::Ok((BigInt::from(-1i128), false)));
+	}
+	
+	#[test]
+	fn big_int_stop() {
+		let array = b"-99999999999999999999999999999999999999999!1x!7";
+		let mut iter = array.iter();
+		
+		let num = BigInt::atoi_iter_wait_stop(&mut iter, b'!').unwrap();
+		assert_eq!((num.is_negative(), num.limbs().len()), (true, 3));
+		assert_eq!(BigInt::atoi_iter_wait_stop(&mut iter, b'!'), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(BigInt::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(BigInt::from(7u128)));
+		
+		assert_eq!(BigInt::atoi_stop(b"-5-", b'-'), Result::Ok(BigInt::from(-5i128)));
+	}
+	
+	#[test]
+	fn big_int_long() {
+		let mut array = Vec::new();
+		for a in 0..20000u32 {
+			array.push(b'0' + (a.wrapping_mul(2654435761) >> 7) as u8 % 10);
+		}
+		assert_eq!(BigInt::atoi(&array).unwrap().limbs(), naive(&array).as_slice());
+		
+		let mut array = vec![b'1'];
+		array.extend(vec![b'0'; 5000]);
+		let mut pow = vec![1];
+		for _ in 0..5000 {
+			mul_small_add(&mut pow, 10, 0);
+		}
+		assert_eq!(BigInt::atoi(&array).unwrap().limbs(), pow.as_slice());
+	}
+}
@@ -0,0 +1,271 @@
+use AtoiErr;
+use AtoiNum;
+use AtoiResult;
+use FromAtoiErr;
+use atoi_skip_stop;
+
+///Behaviour of AtoiFixed on fractional digits beyond the scale.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AtoiExcess {
+	///Stop with `AtoiErr::Inexact` if a discarded digit is not zero.
+	Reject,
+	
+	///Discard the digits (rounding towards zero).
+	Truncate,
+	
+	///Round half away from zero.
+	Round,
+}
+
+///Parsing of the decimal fraction into the integer scaled by `10^scale`, without floats.
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::{AtoiFixed, AtoiExcess, AtoiErr};
+///
+///fn main() {
+///	assert_eq!(i64::atoi_fixed(b"-1234.56", 2, AtoiExcess::Reject), Ok(-123456));
+///	assert_eq!(i64::atoi_fixed(b"12.5", 2, AtoiExcess::Reject), Ok(1250));
+///	assert_eq!(i64::atoi_fixed(b"7", 2, AtoiExcess::Reject), Ok(700));
+///
+///	assert_eq!(u32::atoi_fixed(b"0.125", 2, AtoiExcess::Reject), Err(AtoiErr::Inexact));
+///	assert_eq!(u32::atoi_fixed(b"0.125", 2, AtoiExcess::Truncate), Ok(12));
+///	assert_eq!(i32::atoi_fixed(b"-0.125", 2, AtoiExcess::Round), Ok(-13));
+///
+///	assert_eq!(u8::atoi_fixed(b"2.56", 2, AtoiExcess::Reject), Err(AtoiErr::Overflow));
+///}
+///```
+pub trait AtoiFixed<T> {
+	///`Atoi::atoi` with `scale` fractional digits.
+	#[inline]
+	fn atoi_fixed(array: &[u8], scale: u32, excess: AtoiExcess) -> AtoiResult<T> {
+		Self::atoi_iter_fixed(array.iter(), scale, excess)
+	}
+	
+	///`Atoi::atoi_stop` with `scale` fractional digits.
+	#[inline]
+	fn atoi_stop_fixed(array: &[u8], stop: u8, scale: u32, excess: AtoiExcess) -> AtoiResult<T> {
+		Self::atoi_iter_stop_fixed(array.iter(), stop, scale, excess)
+	}
+	
+	///`Atoi::atoi_wait_stop` with `scale` fractional digits.
+	#[inline]
+	fn atoi_wait_stop_fixed(array: &[u8], stop: u8, scale: u32, excess: AtoiExcess) -> AtoiResult<T> {
+		Self::atoi_iter_wait_stop_fixed(array.iter(), stop, scale, excess)
+	}
+	
+	///`Atoi::atoi_err` with `scale` fractional digits.
+	///
+	///The position of `AtoiErr::Inexact` is the rejected digit, an overflow by the scale or the rounding is at the end of the number.
+	///
+	///```rust
+	///use cluatoi::{AtoiFixed, AtoiExcess, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_fixed_err::<(AtoiErr, usize)>(b"0.125", 2, AtoiExcess::Reject), Err((AtoiErr::Inexact, 4)));
+	///	assert_eq!(u8::atoi_fixed_err::<(AtoiErr, usize)>(b"3.5", 2, AtoiExcess::Reject), Err((AtoiErr::Overflow, 3)));
+	///}
+	///```
+	#[inline]
+	fn atoi_fixed_err<E: FromAtoiErr>(array: &[u8], scale: u32, excess: AtoiExcess) -> Result<T, E> {
+		Self::atoi_iter_fixed_err(array.iter(), scale, excess)
+	}
+	
+	///`Atoi::atoi_stop_err` with `scale` fractional digits.
+	#[inline]
+	fn atoi_stop_fixed_err<E: FromAtoiErr>(array: &[u8], stop: u8, scale: u32, excess: AtoiExcess) -> Result<T, E> {
+		Self::atoi_iter_stop_fixed_err(array.iter(), stop, scale, excess)
+	}
+	
+	///`Atoi::atoi_wait_stop_err` with `scale` fractional digits.
+	#[inline]
+	fn atoi_wait_stop_fixed_err<E: FromAtoiErr>(array: &[u8], stop: u8, scale: u32, excess: AtoiExcess) -> Result<T, E> {
+		Self::atoi_iter_wait_stop_fixed_err(array.iter(), stop, scale, excess)
+	}
+	
+	///`Atoi::atoi_iter` with `scale` fractional digits.
+	#[inline]
+	fn atoi_iter_fixed<'a, I: Iterator<Item=&'a u8>>(iter: I, scale: u32, excess: AtoiExcess) -> AtoiResult<T> {
+		Self::atoi_iter_fixed_err(iter, scale, excess)
+	}
+	
+	///`Atoi::atoi_iter_stop` with `scale` fractional digits.
+	///
+	///```rust
+	///use cluatoi::{AtoiFixed, AtoiExcess};
+	///
+	///fn main() {
+	///	let array = b"19.99;0.5;3";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u64::atoi_iter_stop_fixed(&mut array_iter, b';', 2, AtoiExcess::Reject), Ok(1999));
+	///	assert_eq!(u64::atoi_iter_stop_fixed(&mut array_iter, b';', 2, AtoiExcess::Reject), Ok(50));
+	///	assert_eq!(u64::atoi_iter_stop_fixed(&mut array_iter, b';', 2, AtoiExcess::Reject), Ok(300));
+	///}
+	///```
+	#[inline]
+	fn atoi_iter_stop_fixed<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, scale: u32, excess: AtoiExcess) -> AtoiResult<T> {
+		Self::atoi_iter_stop_fixed_err(iter, end, scale, excess)
+	}
+	
+	///`Atoi::atoi_iter_wait_stop` with `scale` fractional digits.
+	#[inline]
+	fn atoi_iter_wait_stop_fixed<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, scale: u32, excess: AtoiExcess) -> AtoiResult<T> {
+		Self::atoi_iter_wait_stop_fixed_err(iter, end, scale, excess)
+	}
+	
+	///`Atoi::atoi_iter_err` with `scale` fractional digits.
+	fn atoi_iter_fixed_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, scale: u32, excess: AtoiExcess) -> Result<T, E>;
+	
+	///`Atoi::atoi_iter_stop_err` with `scale` fractional digits.
+	fn atoi_iter_stop_fixed_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, scale: u32, excess: AtoiExcess) -> Result<T, E>;
+	
+	///`Atoi::atoi_iter_wait_stop_err` with `scale` fractional digits.
+	fn atoi_iter_wait_stop_fixed_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, scale: u32, excess: AtoiExcess) -> Result<T, E>;
+}
+
+//Accumulation of the digit with the sign of the number.
+#[inline]
+fn push_digit<T: AtoiNum>(result: &T, digit: u8, negative: bool) -> Option<T> {
+	match negative {
+		true => result.checked_mul_radix(10)?.checked_sub_digit(digit),
+		false => result.checked_mul_radix(10)?.checked_add_digit(digit),
+	}
+}
+
+fn atoi_fixed<'a, T, E, I>(mut iter: I, end: Option<u8>, wait: bool, scale: u32, excess: AtoiExcess) -> Result<T, E>
+	where T: AtoiNum, E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let mut result = T::ZERO;
+	let mut zero = true;
+	let mut negative = false;
+	//Digits after the point.
+	let mut fraction = None;
+	let mut round_up = false;
+	let mut pos = 0;
+	
+	let err = loop {
+		let a = match iter.next() {
+			Some(a) => *a,
+			None => break None,
+		};
+		match a {
+			b'-' | b'+' if pos == 0 && T::SIGNED => negative = a == b'-',
+			_ if Some(a) == end => break None,
+			b'.' if fraction.is_none() => fraction = Some(0u32),
+			b'0' ..= b'9' => match fraction {
+				Some(n) if n >= scale => {
+					if n == scale {
+						round_up = a >= b'5';
+					}
+					if a != b'0' && excess == AtoiExcess::Reject {
+						break Some(AtoiErr::Inexact);
+					}
+					fraction = Some(n.saturating_add(1));
+				},
+				_ => {
+					result = match push_digit(&result, a - b'0', negative) {
+						Some(a) => a,
+						None => break Some(AtoiErr::Overflow),
+					};
+					zero &= a == b'0';
+					fraction = fraction.map(|n| n + 1);
+				},
+			},
+			_ => break Some(AtoiErr::ByteUnk(a)),
+		}
+		pos += 1;
+	};
+	
+	if let Some(err) = err {
+		atoi_skip_stop(iter, end, wait);
+		return Err(E::from_atoi_err(err, pos));
+	}
+	
+	//Zero stays zero, any other value overflows after at most the digit count of the type.
+	if !zero {
+		for _ in fraction.unwrap_or(0) .. scale {
+			result = result.checked_mul_radix(10).ok_or_else(|| E::from_atoi_err(AtoiErr::Overflow, pos))?;
+		}
+	}
+	if round_up && excess == AtoiExcess::Round {
+		result = match negative {
+			true => result.checked_sub_digit(1),
+			false => result.checked_add_digit(1),
+		}.ok_or_else(|| E::from_atoi_err(AtoiErr::Overflow, pos))?;
+	}
+	Ok(result)
+}
+
+impl<T: AtoiNum> AtoiFixed<T> for T {
+	#[inline]
+	fn atoi_iter_fixed_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, scale: u32, excess: AtoiExcess) -> Result<T, E> {
+		atoi_fixed(iter, None, false, scale, excess)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_fixed_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, scale: u32, excess: AtoiExcess) -> Result<T, E> {
+		atoi_fixed(iter, Some(end), false, scale, excess)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_fixed_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, scale: u32, excess: AtoiExcess) -> Result<T, E> {
+		atoi_fixed(iter, Some(end), true, scale, excess)
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use Atoi;
+	use I256;
+	use U256;
+	
+	#[test]
+	fn atoi_fixed() {
+		assert_eq!(i64::atoi_fixed(b"-1234.56", 2, AtoiExcess::Reject), Result::Ok(-123456));
+		assert_eq!(i64::atoi_fixed(b"+1234.5", 2, AtoiExcess::Reject), Result::Ok(123450));
+		assert_eq!(i64::atoi_fixed(b".5", 2, AtoiExcess::Reject), Result::Ok(50));
+		assert_eq!(i64::atoi_fixed(b"5.", 2, AtoiExcess::Reject), Result::Ok(500));
+		assert_eq!(i64::atoi_fixed(b"", 2, AtoiExcess::Reject), Result::Ok(0));
+		assert_eq!(i64::atoi_fixed(b"1.2300", 2, AtoiExcess::Reject), Result::Ok(123));
+		assert_eq!(i64::atoi_fixed(b"1.2301", 2, AtoiExcess::Reject), Result::Err(AtoiErr::Inexact));
+		assert_eq!(i64::atoi_fixed(b"1.5", 0, AtoiExcess::Round), Result::Ok(2));
+		assert_eq!(i64::atoi_fixed(b"-1.5", 0, AtoiExcess::Round), Result::Ok(-2));
+		assert_eq!(i64::atoi_fixed(b"-1.49999", 0, AtoiExcess::Round), Result::Ok(-1));
+		assert_eq!(i64::atoi_fixed(b"-1.99", 0, AtoiExcess::Truncate), Result::Ok(-1));
+		assert_eq!(i64::atoi_fixed(b"1.2.3", 2, AtoiExcess::Reject), Result::Err(AtoiErr::ByteUnk(b'.')));
+		assert_eq!(u64::atoi_fixed(b"-1", 2, AtoiExcess::Reject), Result::Err(AtoiErr::ByteUnk(b'-')));
+		
+		assert_eq!(i8::atoi_fixed(b"-1.28", 2, AtoiExcess::Reject), Result::Ok(i8::MIN));
+		assert_eq!(i8::atoi_fixed(b"1.28", 2, AtoiExcess::Reject), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i8::atoi_fixed(b"1.275", 2, AtoiExcess::Round), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i8::atoi_fixed(b"1.275", 2, AtoiExcess::Truncate), Result::Ok(127));
+		assert_eq!(u8::atoi_fixed(b"3", 2, AtoiExcess::Reject), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u64::atoi_fixed(b"0", 40, AtoiExcess::Reject), Result::Ok(0));
+		assert_eq!(u128::atoi_fixed(b"3.4028236692093846346337460743176821145", 38, AtoiExcess::Round), Result::Ok(340282366920938463463374607431768211450));
+		assert_eq!(U256::atoi_fixed(b"1", 77, AtoiExcess::Reject), U256::atoi(format!("1{}", "0".repeat(77)).as_bytes()));
+		
+		//The scale is not walked for zero, and overflows any other value early.
+		assert_eq!(I256::atoi_fixed(b"-0.000", u32::MAX, AtoiExcess::Reject), Result::Ok(I256::ZERO));
+		assert_eq!(i64::atoi_fixed(b"-0.5", u32::MAX, AtoiExcess::Reject), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_fixed(b"0.05", u32::MAX, AtoiExcess::Round), Result::Err(AtoiErr::Overflow));
+	}
+	
+	#[test]
+	fn atoi_fixed_err() {
+		assert_eq!(u32::atoi_fixed_err::<(AtoiErr, usize)>(b"1.2x", 2, AtoiExcess::Reject), Result::Err((AtoiErr::ByteUnk(b'x'), 3)));
+		assert_eq!(i8::atoi_fixed_err::<(AtoiErr, usize)>(b"-1.285", 2, AtoiExcess::Round), Result::Err((AtoiErr::Overflow, 6)));
+		assert_eq!(u32::atoi_stop_fixed_err::<(AtoiErr, usize)>(b"1.5 ", b' ', 1, AtoiExcess::Reject), Result::Ok(15));
+		
+		//The fields of a price list, an error skips the rest of its field.
+		let array = b"1.25;1.255;x;3";
+		let mut iter = array.iter();
+		let mut prices = Vec::new();
+		while iter.len() > 0 {
+			prices.push(u32::atoi_iter_wait_stop_fixed_err::<(AtoiErr, usize), _>(&mut iter, b';', 2, AtoiExcess::Reject));
+		}
+		assert_eq!(prices, vec![Result::Ok(125), Result::Err((AtoiErr::Inexact, 4)), Result::Err((AtoiErr::ByteUnk(b'x'), 0)), Result::Ok(300)]);
+	}
+}
@@ -0,0 +1,160 @@
+use std::iter;
+use AtoiErr;
+use FromAtoiErr;
+use atoi_skip_stop;
+
+//Digits in one u64 chunk, 10^19 < 2^64.
+const CHUNK_DIGITS: usize = 19;
+const CHUNK_POW: [u64; CHUNK_DIGITS + 1] = [
+	1, 10, 100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000, 100_000_000, 1_000_000_000,
+	10_000_000_000, 100_000_000_000, 1_000_000_000_000, 10_000_000_000_000, 100_000_000_000_000,
+	1_000_000_000_000_000, 10_000_000_000_000_000, 100_000_000_000_000_000, 1_000_000_000_000_000_000,
+	10_000_000_000_000_000_000,
+];
+
+//Ok(None) at the end of the number, Err(byte) for an unknown byte.
+#[inline(always)]
+fn next_digit<'a, I: Iterator<Item=&'a u8>>(iter: &mut I, end: Option<u8>) -> Result<Option<u8>, u8> {
+	match iter.next() {
+		Some(a) if Some(*a) == end => Ok(None),
+		Some(a) if a.is_ascii_digit() => Ok(Some(*a - b'0')),
+		Some(a) => Err(*a),
+		None => Ok(None),
+	}
+}
+
+#[inline(never)]
+fn error<'a, E, I>(iter: I, err: AtoiErr, pos: usize, end: Option<u8>, wait: bool) -> E
+	where E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	atoi_skip_stop(iter, end, wait);
+	E::from_atoi_err(err, pos)
+}
+
+//Magnitude up to `max`. While the result is small, digits are accumulated by 19 in u64 and combined
+//with one 128-bit multiplication, then the last digits are checked one by one, so the overflow is
+//reported at the same byte as by the digit by digit parsing.
+fn magnitude<'a, E, I>(mut iter: I, mut pos: usize, max: u128, end: Option<u8>, wait: bool) -> Result<u128, E>
+	where E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let chunk_pow = CHUNK_POW[CHUNK_DIGITS] as u128;
+	//result * 10^19 + 19 digits <= max
+	let safe = (max - (chunk_pow - 1)) / chunk_pow;
+	
+	let mut result = 0u128;
+	while result <= safe {
+		let mut chunk = 0u64;
+		let mut digits = 0;
+		while digits < CHUNK_DIGITS {
+			match next_digit(&mut iter, end) {
+				Ok(Some(digit)) => chunk = chunk * 10 + digit as u64,
+				Ok(None) => return Ok(result * CHUNK_POW[digits] as u128 + chunk as u128),
+				Err(a) => return Err(error(iter, AtoiErr::ByteUnk(a), pos + digits, end, wait)),
+			}
+			digits += 1;
+		}
+		result = result * chunk_pow + chunk as u128;
+		pos += CHUNK_DIGITS;
+	}
+	
+	loop {
+		match next_digit(&mut iter, end) {
+			Ok(Some(digit)) => match result.checked_mul(10).and_then(|s| s.checked_add(digit as u128)) {
+				Some(s) if s <= max => result = s,
+				_ => return Err(error(iter, AtoiErr::Overflow, pos, end, wait)),
+			},
+			Ok(None) => return Ok(result),
+			Err(a) => return Err(error(iter, AtoiErr::ByteUnk(a), pos, end, wait)),
+		}
+		pos += 1;
+	}
+}
+
+pub fn atoi_u128<'a, E, I>(iter: I, end: Option<u8>, wait: bool) -> Result<u128, E>
+	where E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	magnitude(iter, 0, u128::MAX, end, wait)
+}
+
+pub fn atoi_i128<'a, E, I>(mut iter: I, end: Option<u8>, wait: bool) -> Result<i128, E>
+	where E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let a = match iter.next() {
+		Some(a) => a,
+		None => return Ok(0),
+	};
+	match *a {
+		b'-' => magnitude(iter, 1, i128::MIN.unsigned_abs(), end, wait).map(|a| (a as i128).wrapping_neg()),
+		b'+' => magnitude(iter, 1, i128::MAX as u128, end, wait).map(|a| a as i128),
+		_ => magnitude(iter::once(a).chain(iter), 0, i128::MAX as u128, end, wait).map(|a| a as i128),
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use Atoi;
+	use AtoiErr;
+	use AtoiNum;
+	
+	//The digit by digit parsing of the default AtoiNum implementation.
+	macro_rules! slow_build {
+		($slow:ident, $t:ty, $signed:expr) => {
+			#[derive(Debug, PartialEq)]
+			struct $slow($t);
+			
+			impl AtoiNum for $slow {
+				const ZERO: Self = $slow(0);
+				const SIGNED: bool = $signed;
+				
+				fn checked_mul_radix(&self, radix: u8) -> Option<Self> {
+					self.0.checked_mul(radix as $t).map($slow)
+				}
+				fn checked_add_digit(&self, digit: u8) -> Option<Self> {
+					self.0.checked_add(digit as $t).map($slow)
+				}
+				fn checked_sub_digit(&self, digit: u8) -> Option<Self> {
+					self.0.checked_sub(digit as $t).map($slow)
+				}
+			}
+			
+			atoi_build_num!($slow);
+		};
+	}
+	slow_build!(SlowU, u128, false);
+	slow_build!(SlowI, i128, true);
+	
+	const ARRAYS: &[&[u8]] = &[
+		b"", b"0", b"-0", b"+", b"-", b"-1", b"+-1", b"12a34", b"1!2", b"!",
+		b"00000000000000000000000000000000000000000000000001",
+		b"1234567890123456789", b"12345678901234567890", b"-1234567890123456789012345678901234567!9",
+		b"340282366920938463463374607431768211455", b"340282366920938463463374607431768211456",
+		b"3402823669209384634633746074317682114550", b"340282366920938463463374607431768211456!5",
+		b"170141183460469231731687303715884105727", b"170141183460469231731687303715884105728",
+		b"-170141183460469231731687303715884105728", b"-170141183460469231731687303715884105729!1",
+		b"+170141183460469231731687303715884105727x", b"99999999999999999999999999999999999999999999x!1",
+	];
+	
+	#[test]
+	fn int128_chunked() {
+		for array in ARRAYS {
+			macro_rules! check {
+				($t:ty, $slow:ident, $f:ident $(, $end:expr)*) => {{
+					let mut iter = array.iter();
+					let fast = <$t>::$f::<(AtoiErr, usize), _>(&mut iter $(, $end)*);
+					let fast = (fast, iter.len());
+					
+					let mut iter = array.iter();
+					let slow = $slow::$f::<(AtoiErr, usize), _>(&mut iter $(, $end)*).map(|a| a.0);
+					assert_eq!(fast, (slow, iter.len()), "{}", String::from_utf8_lossy(array));
+				}};
+			}
+			check!(u128, SlowU, atoi_iter_err);
+			check!(u128, SlowU, atoi_iter_stop_err, b'!');
+			check!(u128, SlowU, atoi_iter_wait_stop_err, b'!');
+			check!(i128, SlowI, atoi_iter_err);
+			check!(i128, SlowI, atoi_iter_stop_err, b'!');
+			check!(i128, SlowI, atoi_iter_wait_stop_err, b'!');
+		}
+	}
+}
@@ -75,13 +75,83 @@ fn main() {
 
 */
 
+#![allow(clippy::tabs_in_doc_comments, clippy::needless_doctest_main)]
+
+///Implementation of `Atoi` for the types implementing `AtoiNum`.
+///
+///`Atoi` is implemented for references of any `Atoi` type, so it cannot be implemented for every `AtoiNum` type at once.
+#[macro_export]
+macro_rules! atoi_build_num {
+	($($t:ty),+) => {
+		$(
+		impl $crate::Atoi<$t> for $t {
+			#[inline(always)]
+			fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> $crate::AtoiResult< $t > {
+				<$t as $crate::AtoiNum>::atoi_num_iter_err(iter)
+			}
+			
+			#[inline(always)]
+			fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> $crate::AtoiResult< $t > {
+				<$t as $crate::AtoiNum>::atoi_num_iter_stop_err(iter, end)
+			}
+			
+			#[inline(always)]
+			fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> $crate::AtoiResult< $t > {
+				<$t as $crate::AtoiNum>::atoi_num_iter_wait_stop_err(iter, end)
+			}
+			
+			#[inline(always)]
+			fn atoi_iter_err<'a, E: $crate::FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result< $t, E > {
+				<$t as $crate::AtoiNum>::atoi_num_iter_err(iter)
+			}
+			
+			#[inline(always)]
+			fn atoi_iter_stop_err<'a, E: $crate::FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result< $t, E > {
+				<$t as $crate::AtoiNum>::atoi_num_iter_stop_err(iter, end)
+			}
+			
+			#[inline(always)]
+			fn atoi_iter_wait_stop_err<'a, E: $crate::FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result< $t, E > {
+				<$t as $crate::AtoiNum>::atoi_num_iter_wait_stop_err(iter, end)
+			}
+		}
+		)+
+	};
+}
+
+mod any_int;
+mod int128;
+mod neg;
+mod range;
+mod non_zero;
+mod wrapping;
+mod wide;
+mod atof;
+mod fixed;
+mod sci;
+mod ratio;
+#[cfg(feature = "bigint")]
+mod big_int;
+
+pub use any_int::{AnyInt, AnyIntKind, AnyIntPref, AtoiDiagnose, AtoiDiagErr};
+pub use wide::{U256, U512, I256, I512};
+pub use neg::AtoiNeg;
+pub use range::Bounded;
+pub use atof::Atof;
+pub use fixed::{AtoiFixed, AtoiExcess};
+pub use sci::AtoiSci;
+pub use ratio::AtoiRatio;
+
+use std::ops::RangeBounds;
+#[cfg(feature = "bigint")]
+pub use big_int::BigInt;
 
 ///Type result Atoi.
 pub type AtoiResult<T> = Result<T, AtoiErr>;
 
 #[doc(hidden)]
 #[allow(non_camel_case_types)]
-pub type cluAtoi<T> = Atoi<T>;
+pub type cluAtoi<T> = dyn Atoi<T>;
 
 ///Parsing the byte sequence of the ascii characters and safely converting them to integers.
 pub trait Atoi<T> {
@@ -97,7 +167,7 @@ pub trait Atoi<T> {
 	///}
 	///```
 	#[inline]
-	fn atoi<'a>(array: &'a [u8]) -> AtoiResult<T> {
+	fn atoi(array: &[u8]) -> AtoiResult<T> {
 		Self::atoi_iter(array.iter())
 	}
 	
@@ -110,66 +180,53 @@ pub trait Atoi<T> {
 	///fn main() {
 	///	let array = b"A1024~0";
 	///
-	///	let mut array_iter = array.iter();
-	///	let _ignore = array_iter.next();	//A
-	///
-	///	if let Ok(num) = isize::atoi_stop(&mut array_iter, b'~') {	
+	///	if let Ok(num) = isize::atoi_stop(&array[1..], b'~') {	
 	///		//1024isize
 	///
 	///		println!("TEST {}", num);
 	///	}
-	///	
-	///	let index =  array_iter.next();	
-	///	//0
 	///}
 	///```
 	///
 	///```rust
-	///fn main() {
-	///	let array = b"A1024~0";
+	///use cluatoi::Atoi;
 	///
-	///	let mut array_iter = array.iter();
-	///	let _ignore = array_iter.next();	//A
+	///fn main() {
+	///	let array = b"A-1024~0";
 	///
 	///	//USIZE!!!
-	///	if let Ok(num) = usize::atoi_stop(&mut array_iter, b'~') {	
-	///		// AtoiErr::UnkSymbol(b'-')
+	///	if let Ok(num) = usize::atoi_stop(&array[1..], b'~') {	
+	///		// AtoiErr::ByteUnk(b'-')
 	///
 	///		println!("TEST {}", num);
 	///	}
-	///	
-	///	let index =  array_iter.next();	//1
 	///}
 	///```
 	///
 	#[inline]
-	fn atoi_stop<'a>(array: &'a [u8], stop: u8) -> AtoiResult<T> {
+	fn atoi_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
 		Self::atoi_iter_stop(array.iter(), stop)
 	}
 	
 	///An array analysis using an iterator and waiting for an "X" character even if an error occurred.
 	///
 	///```rust
+	///use cluatoi::Atoi;
+	///
 	///fn main() {
 	///	let array = b"A-1024~0";
 	///
-	///	let mut array_iter = array.iter();
-	///	let _ignore = array_iter.next();	//A
-	///
 	///	//USIZE!!!
-	///	if let Ok(num) = usize::atoi_wait_stop(&mut array_iter, b'~') {	
-	///		// AtoiErr::UnkSymbol(b'-')
+	///	if let Ok(num) = usize::atoi_wait_stop(&array[1..], b'~') {	
+	///		// AtoiErr::ByteUnk(b'-')
 	///
 	///		println!("TEST {}", num);
 	///	}
-	///	
-	///	let index =  array_iter.next();	
-	///	//->0	?NEXT -> END ARRAY.
 	///}
 	///```
 	///
 	#[inline]
-	fn atoi_wait_stop<'a>(array: &'a [u8], stop: u8) -> AtoiResult<T> {				
+	fn atoi_wait_stop(array: &[u8], stop: u8) -> AtoiResult<T> {				
 		Self::atoi_iter_wait_stop(array.iter(), stop)
 	}
 	
@@ -177,6 +234,8 @@ pub trait Atoi<T> {
 	///Array parsing using an iterator.
 	///
 	///```rust
+	///use cluatoi::Atoi;
+	///
 	///fn main() {
 	///	let array = b"A1024";
 	///
@@ -196,6 +255,8 @@ pub trait Atoi<T> {
 	///Array parsing using an iterator and stopping on the 'X' character.
 	///
 	///```rust
+	///use cluatoi::Atoi;
+	///
 	///fn main() {
 	///	let array = b"A1024~0";
 	///
@@ -213,6 +274,8 @@ pub trait Atoi<T> {
 	///```
 	///
 	///```rust
+	///use cluatoi::Atoi;
+	///
 	///fn main() {
 	///	let array = b"A-1024~0";
 	///
@@ -221,7 +284,7 @@ pub trait Atoi<T> {
 	///
 	///	//USIZE!!!
 	///	if let Ok(num) = usize::atoi_iter_stop(&mut array_iter, b'~') {	
-	///		// AtoiErr::UnkSymbol(b'-')
+	///		// AtoiErr::ByteUnk(b'-')
 	///
 	///		println!("TEST {}", num);
 	///	}
@@ -238,6 +301,8 @@ pub trait Atoi<T> {
 	///An array analysis using an iterator and waiting for an "X" character even if an error occurred.
 	///
 	///```rust
+	///use cluatoi::Atoi;
+	///
 	///fn main() {
 	///	let array = b"A-1024~0";
 	///
@@ -246,7 +311,7 @@ pub trait Atoi<T> {
 	///
 	///	//USIZE!!!
 	///	if let Ok(num) = usize::atoi_iter_wait_stop(&mut array_iter, b'~') {	
-	///		// AtoiErr::UnkSymbol(b'-')
+	///		// AtoiErr::ByteUnk(b'-')
 	///
 	///		println!("TEST {}", num);
 	///	}
@@ -257,99 +322,1637 @@ pub trait Atoi<T> {
 	///```
 	///
 	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T>;
-}
-
-impl<'l, A: Atoi<T>, T> Atoi<T> for &'l A {
-	#[inline(always)]
-	fn atoi<'a>(array: &'a [u8]) -> AtoiResult<T> {
-		A::atoi(array)
+	
+	
+	///Array parsing with the error type of the caller.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, FromAtoiErr};
+	///
+	///#[derive(Debug, PartialEq)]
+	///enum MyErr {
+	///	Port(AtoiErr, usize),
+	///}
+	///
+	///impl FromAtoiErr for MyErr {
+	///	fn from_atoi_err(err: AtoiErr, pos: usize) -> Self {
+	///		MyErr::Port(err, pos)
+	///	}
+	///}
+	///
+	///fn main() {
+	///	assert_eq!(u16::atoi_err::<MyErr>(b"80x"), Err(MyErr::Port(AtoiErr::ByteUnk(b'x'), 2)));
+	///	assert_eq!(u16::atoi_err::<MyErr>(b"65536"), Err(MyErr::Port(AtoiErr::Overflow, 4)));
+	///}
+	///```
+	#[inline]
+	fn atoi_err<E: FromAtoiErr>(array: &[u8]) -> Result<T, E> {
+		Self::atoi_iter_err(array.iter())
 	}
 	
-	#[inline(always)]
-	fn atoi_stop<'a>(array: &'a [u8], stop: u8) -> AtoiResult<T> {
-		A::atoi_stop(array, stop)
+	///Array parsing and stopping on the 'X' character with the error type of the caller.
+	#[inline]
+	fn atoi_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		Self::atoi_iter_stop_err(array.iter(), stop)
 	}
 	
-	#[inline(always)]
-	fn atoi_wait_stop<'a>(array: &'a [u8], stop: u8) -> AtoiResult<T> {
-		A::atoi_wait_stop(array, stop)
+	///An array analysis waiting for an "X" character even if an error occurred with the error type of the caller.
+	#[inline]
+	fn atoi_wait_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		Self::atoi_iter_wait_stop_err(array.iter(), stop)
 	}
 	
-	#[inline(always)]
-	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T> {
-		A::atoi_iter(iter)
+	///Array parsing using an iterator with the error type of the caller.
+	///
+	///The position of the erroneous byte is counted from the beginning of the iterator.
+	///By default it is the last byte read by `atoi_iter` for `AtoiErr::ByteUnk` and `AtoiErr::Overflow` and 0 for other errors,
+	///types that know the exact position can override it.
+	fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, E> {
+		let mut iter = AtoiTrack::new(iter);
+		Self::atoi_iter(&mut iter).map_err(|err| iter.err(err))
 	}
 	
-	#[inline(always)]
-	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
-		A::atoi_iter_stop(iter, end)
+	///Array parsing using an iterator and stopping on the 'X' character with the error type of the caller.
+	fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		let mut iter = AtoiTrack::new(iter);
+		Self::atoi_iter_stop(&mut iter, end).map_err(|err| iter.err(err))
 	}
 	
-	#[inline(always)]
-	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
-		A::atoi_iter_wait_stop(iter, end)
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred with the error type of the caller.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///fn main() {
+	///	let array = b"1!2x3!";
+	///	let mut array_iter = array.iter();
+	///
+	///	//(AtoiErr, position)
+	///	assert_eq!(u8::atoi_iter_wait_stop_err::<(AtoiErr, usize), _>(&mut array_iter, b'!'), Ok(1));
+	///	assert_eq!(u8::atoi_iter_wait_stop_err::<(AtoiErr, usize), _>(&mut array_iter, b'!'), Err((AtoiErr::ByteUnk(b'x'), 1)));
+	///	assert_eq!(array_iter.next(), None);
+	///}
+	///```
+	///
+	///By default the number is parsed by `atoi_iter_stop`, after `AtoiErr::ByteUnk` and `AtoiErr::Overflow` the rest of the field is skipped.
+	fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		let mut iter = AtoiTrack::new(iter);
+		Self::atoi_iter_stop(&mut iter, end).map_err(|err| match err {
+			AtoiErr::ByteUnk(_) | AtoiErr::Overflow => {
+				let err = iter.err(err);
+				atoi_skip_stop(iter.by_ref(), Some(end), true);
+				err
+			},
+			_ => iter.err(err),
+		})
 	}
-}
-impl<'l, A: Atoi<T>, T> Atoi<T> for &'l mut A {
-	#[inline(always)]
-	fn atoi<'a>(array: &'a [u8]) -> AtoiResult<T> {
-		A::atoi(array)
+	
+	
+	///Parsing the longest numeric prefix of the array, like C `strtol`, returns the value and the unconsumed remainder.
+	///
+	///The remainder begins at the first byte that is not a digit. An error is returned only if the array does not begin with a number (`AtoiErr::NoDigits`) or the prefix overflows (`AtoiErr::Overflow`). Unsigned types accept the '+' sign and reject '-'.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_prefix(b"123abc"), Ok((123, &b"abc"[..])));
+	///	assert_eq!(i32::atoi_prefix(b"-7,8"), Ok((-7, &b",8"[..])));
+	///
+	///	assert_eq!(u32::atoi_prefix(b"+5 apples"), Ok((5, &b" apples"[..])));
+	///
+	///	assert_eq!(u32::atoi_prefix(b"abc"), Err(AtoiErr::NoDigits));
+	///	//USIZE!!!
+	///	assert_eq!(usize::atoi_prefix(b"-7"), Err(AtoiErr::NoDigits));
+	///}
+	///```
+	fn atoi_prefix(array: &[u8]) -> AtoiResult<(T, &[u8])> {
+		let sign = match array.first() {
+			Some(b'-') | Some(b'+') => 1,
+			_ => 0,
+		};
+		let end = sign + array[sign..].iter().take_while(|a| a.is_ascii_digit()).count();
+		if end == sign {
+			return Err(AtoiErr::NoDigits);
+		}
+		
+		//'+' is accepted by unsigned types as in strtoul
+		let start = match array[0] {
+			b'+' => 1,
+			_ => 0,
+		};
+		match Self::atoi(&array[start..end]) {
+			Ok(num) => Ok((num, &array[end..])),
+			//the sign of an unsigned type
+			Err(AtoiErr::ByteUnk(_)) => Err(AtoiErr::NoDigits),
+			Err(e) => Err(e),
+		}
 	}
 	
-	#[inline(always)]
-	fn atoi_stop<'a>(array: &'a [u8], stop: u8) -> AtoiResult<T> {
-		A::atoi_stop(array, stop)
+	
+	///Array parsing and stopping on the 'X' character, returns the reason of the stop.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiStop};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_stop_reason(b"12!3", b'!'), Ok((12, AtoiStop::Byte(2))));
+	///	assert_eq!(u32::atoi_stop_reason(b"3", b'!'), Ok((3, AtoiStop::End)));
+	///}
+	///```
+	#[inline]
+	fn atoi_stop_reason(array: &[u8], stop: u8) -> AtoiResult<(T, AtoiStop)> {
+		Self::atoi_iter_stop_reason(array.iter(), stop)
 	}
 	
-	#[inline(always)]
-	fn atoi_wait_stop<'a>(array: &'a [u8], stop: u8) -> AtoiResult<T> {
-		A::atoi_wait_stop(array, stop)
+	///An array analysis waiting for an "X" character even if an error occurred, returns the reason of the stop.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, AtoiStop};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_wait_stop_reason(b"-12!3", b'!'), (Err(AtoiErr::ByteUnk(b'-')), AtoiStop::Byte(3)));
+	///	assert_eq!(u32::atoi_wait_stop_reason(b"-3", b'!'), (Err(AtoiErr::ByteUnk(b'-')), AtoiStop::End));
+	///}
+	///```
+	#[inline]
+	fn atoi_wait_stop_reason(array: &[u8], stop: u8) -> (AtoiResult<T>, AtoiStop) {
+		Self::atoi_iter_wait_stop_reason(array.iter(), stop)
 	}
 	
-	#[inline(always)]
-	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T> {
-		A::atoi_iter(iter)
+	///Array parsing using an iterator and stopping on the 'X' character, returns the reason of the stop.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiStop};
+	///
+	///fn main() {
+	///	let array = b"1!2!3";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u8::atoi_iter_stop_reason(&mut array_iter, b'!'), Ok((1, AtoiStop::Byte(1))));
+	///	assert_eq!(u8::atoi_iter_stop_reason(&mut array_iter, b'!'), Ok((2, AtoiStop::Byte(1))));
+	///
+	///	//the last field is not terminated
+	///	assert_eq!(u8::atoi_iter_stop_reason(&mut array_iter, b'!'), Ok((3, AtoiStop::End)));
+	///}
+	///```
+	fn atoi_iter_stop_reason<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, AtoiStop)> {
+		let mut iter = AtoiTrack::new(iter);
+		let num = Self::atoi_iter_stop(&mut iter, end)?;
+		
+		Ok((num, iter.stop()))
 	}
 	
-	#[inline(always)]
-	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
-		A::atoi_iter_stop(iter, end)
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred, returns the reason of the stop.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, AtoiStop};
+	///
+	///fn main() {
+	///	let array = b"1!x2!3";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u8::atoi_iter_wait_stop_reason(&mut array_iter, b'!'), (Ok(1), AtoiStop::Byte(1)));
+	///	assert_eq!(u8::atoi_iter_wait_stop_reason(&mut array_iter, b'!'), (Err(AtoiErr::ByteUnk(b'x')), AtoiStop::Byte(2)));
+	///	assert_eq!(u8::atoi_iter_wait_stop_reason(&mut array_iter, b'!'), (Ok(3), AtoiStop::End));
+	///}
+	///```
+	fn atoi_iter_wait_stop_reason<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> (AtoiResult<T>, AtoiStop) {
+		let mut iter = AtoiTrack::new(iter);
+		let result = Self::atoi_iter_wait_stop(&mut iter, end);
+		
+		(result, iter.stop())
 	}
 	
-	#[inline(always)]
-	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
-		A::atoi_iter_wait_stop(iter, end)
+	
+	///Array parsing and stopping on the 'X' character, the 'X' character is required.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_stop_strict(b"5:hello", b':'), Ok(5));
+	///	assert_eq!(u32::atoi_stop_strict(b"5", b':'), Err(AtoiErr::MissingStop));
+	///}
+	///```
+	#[inline]
+	fn atoi_stop_strict(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atoi_iter_stop_strict(array.iter(), stop)
 	}
-}
-
-///Result trait Atoi
-#[derive(Debug, PartialEq, Clone)]
-pub enum AtoiErr {
-	///Overflow of number.
+	
+	///An array analysis waiting for an "X" character even if an error occurred, the 'X' character is required.
+	#[inline]
+	fn atoi_wait_stop_strict(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atoi_iter_wait_stop_strict(array.iter(), stop)
+	}
+	
+	///Array parsing using an iterator and stopping on the 'X' character, the 'X' character is required.
 	///
 	///```rust
-	///assert_eq!(u8::atoi(b"256"), Result::Err(AtoiErr::Overflow));
-	///assert_eq!(u16::atoi(b"65536"), Result::Err(AtoiErr::Overflow));
-	///assert_eq!(u32::atoi(b"4294967296"), Result::Err(AtoiErr::Overflow));
-	///assert_eq!(u64::atoi(b"18446744073709551616"), Result::Err(AtoiErr::Overflow));
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///fn main() {
+	///	let array = b"5:hello";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u32::atoi_iter_stop_strict(&mut array_iter, b':'), Ok(5));
+	///	assert_eq!(array_iter.next(), Some(&b'h'));
+	///}
 	///```
-	Overflow,
+	fn atoi_iter_stop_strict<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		match Self::atoi_iter_stop_reason(iter, end)? {
+			(num, AtoiStop::Byte(_)) => Ok(num),
+			(_, AtoiStop::End) => Err(AtoiErr::MissingStop),
+		}
+	}
 	
-	///In the byte sequence, an unknown character was used.
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred, the 'X' character is required.
+	///
+	///The error of the number takes precedence over `AtoiErr::MissingStop`.
 	///
 	///```rust
-	///assert_eq!(u8::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
-	///assert_eq!(u16::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
-	///assert_eq!(u32::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
-	///assert_eq!(u64::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
+	///use cluatoi::{Atoi, AtoiErr};
 	///
-	///assert_eq!(u8::atoi(b"128U"), Result::Err(AtoiErr::ByteUnk(b'U')));
-	///assert_eq!(u16::atoi(b"128L"), Result::Err(AtoiErr::ByteUnk(b'L')));
-	///assert_eq!(u32::atoi(b"128I"), Result::Err(AtoiErr::ByteUnk(b'I')));
-	///assert_eq!(u64::atoi(b"128N"), Result::Err(AtoiErr::ByteUnk(b'N')));
+	///fn main() {
+	///	let array = b"1!2";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u32::atoi_iter_wait_stop_strict(&mut array_iter, b'!'), Ok(1));
+	///	assert_eq!(u32::atoi_iter_wait_stop_strict(&mut array_iter, b'!'), Err(AtoiErr::MissingStop));
+	///}
 	///```
+	fn atoi_iter_wait_stop_strict<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		match Self::atoi_iter_wait_stop_reason(iter, end) {
+			(Ok(_), AtoiStop::End) => Err(AtoiErr::MissingStop),
+			(result, _) => result,
+		}
+	}
+	
+	
+	///Array parsing using a cloneable iterator, the iterator is advanced only on success.
 	///
-	ByteUnk(u8),
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///fn main() {
+	///	let array = b"true";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u8::atoi_iter_try(&mut array_iter), Err(AtoiErr::ByteUnk(b't')));
+	///	assert_eq!(array_iter.as_slice(), b"true");
+	///}
+	///```
+	fn atoi_iter_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I) -> AtoiResult<T> {
+		let mut try_iter = iter.clone();
+		let num = Self::atoi_iter(&mut try_iter)?;
+		
+		*iter = try_iter;
+		Ok(num)
+	}
+	
+	///Array parsing using a cloneable iterator and stopping on the 'X' character, the iterator is advanced only on success.
+	///
+	///```rust
+	///use cluatoi::Atoi;
+	///
+	///fn main() {
+	///	let array = b"12!none!";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u8::atoi_iter_stop_try(&mut array_iter, b'!'), Ok(12));
+	///	assert!(u8::atoi_iter_stop_try(&mut array_iter, b'!').is_err());
+	///	assert_eq!(array_iter.as_slice(), b"none!");
+	///}
+	///```
+	fn atoi_iter_stop_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8) -> AtoiResult<T> {
+		let mut try_iter = iter.clone();
+		let num = Self::atoi_iter_stop(&mut try_iter, end)?;
+		
+		*iter = try_iter;
+		Ok(num)
+	}
+	
+	///An array analysis using a cloneable iterator and waiting for an "X" character, the iterator is advanced only on success.
+	fn atoi_iter_wait_stop_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8) -> AtoiResult<T> {
+		let mut try_iter = iter.clone();
+		let num = Self::atoi_iter_wait_stop(&mut try_iter, end)?;
+		
+		*iter = try_iter;
+		Ok(num)
+	}
+	
+	
+	///Parsing the list of numbers separated by the 'X' character, collecting every error in one pass.
+	///
+	///Each field is parsed as with `atoi_iter_wait_stop`, an empty field is `AtoiErr::NoDigits`. At most `max_errors` errors are collected, the rest are only counted. A stop byte at the end of the array terminates the last field.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, AtoiListErr};
+	///
+	///fn main() {
+	///	let list = u8::atoi_list(b"1!x!300!4", b'!', 16);
+	///
+	///	assert_eq!(list.values, vec![1, 4]);
+	///	assert_eq!(list.errors, vec![
+	///		AtoiListErr { field: 1, offset: 2, err: AtoiErr::ByteUnk(b'x') },
+	///		AtoiListErr { field: 2, offset: 6, err: AtoiErr::Overflow },
+	///	]);
+	///	assert_eq!(list.skipped_errors, 0);
+	///}
+	///```
+	fn atoi_list(array: &[u8], stop: u8, max_errors: usize) -> AtoiList<T> {
+		let mut list = AtoiList {
+			values: Vec::new(),
+			errors: Vec::new(),
+			skipped_errors: 0,
+		};
+		
+		let mut iter = array.iter();
+		let mut field = 0;
+		let mut offset = 0;
+		while !iter.as_slice().is_empty() {
+			let empty = iter.as_slice()[0] == stop;
+			let mut track = AtoiTrack::new(&mut iter);
+			let result = match empty {
+				true => {
+					track.next();
+					Err((AtoiErr::NoDigits, 0))
+				},
+				false => Self::atoi_iter_wait_stop_err::<(AtoiErr, usize), _>(&mut track, stop),
+			};
+			match result {
+				Ok(num) => list.values.push(num),
+				Err((err, pos)) => match list.errors.len() < max_errors {
+					true => list.errors.push(AtoiListErr { field, offset: offset + pos, err }),
+					false => list.skipped_errors += 1,
+				},
+			}
+			
+			match track.stop() {
+				AtoiStop::Byte(pos) => offset += pos + 1,
+				AtoiStop::End => break,
+			}
+			field += 1;
+		}
+		
+		list
+	}
+	
+	
+	///Array parsing, returns the value with the information about its notation.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiInfo};
+	///
+	///fn main() {
+	///	let (num, info) = i32::atoi_info(b"+007").unwrap();
+	///
+	///	assert_eq!(num, 7);
+	///	assert_eq!(info, AtoiInfo {
+	///		sign: Some(b'+'),
+	///		leading_zeros: 2,
+	///		digits: 3,
+	///		radix: 10,
+	///		consumed: 4,
+	///	});
+	///}
+	///```
+	#[inline]
+	fn atoi_info(array: &[u8]) -> AtoiResult<(T, AtoiInfo)> {
+		Self::atoi_iter_info(array.iter())
+	}
+	
+	///Array parsing and stopping on the 'X' character, returns the value with the information about its notation.
+	#[inline]
+	fn atoi_stop_info(array: &[u8], stop: u8) -> AtoiResult<(T, AtoiInfo)> {
+		Self::atoi_iter_stop_info(array.iter(), stop)
+	}
+	
+	///Array parsing using an iterator, returns the value with the information about its notation.
+	fn atoi_iter_info<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<(T, AtoiInfo)> {
+		let mut iter = AtoiInfoTrack::new(iter, None);
+		let num = Self::atoi_iter(&mut iter)?;
+		
+		Ok((num, iter.info()))
+	}
+	
+	///Array parsing using an iterator and stopping on the 'X' character, returns the value with the information about its notation.
+	///
+	///The stop byte is not included in `AtoiInfo::consumed`.
+	///
+	///```rust
+	///use cluatoi::Atoi;
+	///
+	///fn main() {
+	///	let array = b"0012!-0!";
+	///	let mut array_iter = array.iter();
+	///
+	///	let (num, info) = i8::atoi_iter_stop_info(&mut array_iter, b'!').unwrap();
+	///	assert_eq!((num, info.sign, info.leading_zeros, info.consumed), (12, None, 2, 4));
+	///
+	///	let (num, info) = i8::atoi_iter_stop_info(&mut array_iter, b'!').unwrap();
+	///	assert_eq!((num, info.sign, info.leading_zeros, info.consumed), (0, Some(b'-'), 0, 2));
+	///}
+	///```
+	fn atoi_iter_stop_info<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, AtoiInfo)> {
+		let mut iter = AtoiInfoTrack::new(iter, Some(end));
+		let num = Self::atoi_iter_stop(&mut iter, end)?;
+		
+		Ok((num, iter.info()))
+	}
+	
+	///Array parsing with the check of the range of values.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, AnyInt};
+	///
+	///fn main() {
+	///	assert_eq!(u16::atoi_range(b"8080", 1..), Ok(8080));
+	///	assert_eq!(u16::atoi_range(b"0", 1..), Err(AtoiErr::OutOfRange(AnyInt::U16(0))));
+	///	assert_eq!(u8::atoi_range(b"101", 0..=100), Err(AtoiErr::OutOfRange(AnyInt::U8(101))));
+	///}
+	///```
+	#[inline]
+	fn atoi_range<R: RangeBounds<T>>(array: &[u8], range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		Self::atoi_iter_range(array.iter(), range)
+	}
+	
+	///Array parsing and stopping on the 'X' character with the check of the range of values.
+	#[inline]
+	fn atoi_stop_range<R: RangeBounds<T>>(array: &[u8], stop: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		Self::atoi_iter_stop_range(array.iter(), stop, range)
+	}
+	
+	///An array analysis waiting for an "X" character even if an error occurred with the check of the range of values.
+	#[inline]
+	fn atoi_wait_stop_range<R: RangeBounds<T>>(array: &[u8], stop: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		Self::atoi_iter_wait_stop_range(array.iter(), stop, range)
+	}
+	
+	///Array parsing using an iterator with the check of the range of values.
+	#[inline]
+	fn atoi_iter_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		atoi_range_check(Self::atoi_iter(iter)?, range)
+	}
+	
+	///Array parsing using an iterator and stopping on the 'X' character with the check of the range of values.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, AnyInt};
+	///
+	///fn main() {
+	///	let array = b"12:61:00";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u8::atoi_iter_stop_range(&mut array_iter, b':', 0..24), Ok(12));
+	///	assert_eq!(u8::atoi_iter_stop_range(&mut array_iter, b':', 0..60), Err(AtoiErr::OutOfRange(AnyInt::U8(61))));
+	///	assert_eq!(u8::atoi_iter_stop_range(&mut array_iter, b':', 0..60), Ok(0));
+	///}
+	///```
+	#[inline]
+	fn atoi_iter_stop_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, end: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		atoi_range_check(Self::atoi_iter_stop(iter, end)?, range)
+	}
+	
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred with the check of the range of values.
+	#[inline]
+	fn atoi_iter_wait_stop_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, end: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		atoi_range_check(Self::atoi_iter_wait_stop(iter, end)?, range)
+	}
+	
+	///Array parsing, the sentinels of the missing value (for example `ATOI_NULLS`) are `None`.
+	///
+	///```rust
+	///use cluatoi::{Atoi, ATOI_NULLS};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_null(b"\\N", ATOI_NULLS), Ok(None));
+	///	assert_eq!(u32::atoi_null(b"", ATOI_NULLS), Ok(None));
+	///	assert_eq!(u32::atoi_null(b"42", ATOI_NULLS), Ok(Some(42)));
+	///	assert_eq!(u32::atoi_null(b"n/a", &[b"n/a"]), Ok(None));
+	///}
+	///```
+	#[inline]
+	fn atoi_null(array: &[u8], nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		Self::atoi_iter_null(&mut array.iter(), nulls)
+	}
+	
+	///Array parsing and stopping on the 'X' character, the sentinels of the missing value are `None`.
+	#[inline]
+	fn atoi_stop_null(array: &[u8], stop: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		Self::atoi_iter_stop_null(&mut array.iter(), stop, nulls)
+	}
+	
+	///An array analysis waiting for an "X" character even if an error occurred, the sentinels of the missing value are `None`.
+	#[inline]
+	fn atoi_wait_stop_null(array: &[u8], stop: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		Self::atoi_iter_wait_stop_null(&mut array.iter(), stop, nulls)
+	}
+	
+	///Array parsing using an iterator, the sentinels of the missing value are `None`.
+	///
+	///The iterator is cloned to look for the sentinels, a sentinel is consumed entirely.
+	fn atoi_iter_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		match atoi_null_len(iter, None, nulls) {
+			Some(_) => {
+				for _ in iter {}
+				Ok(None)
+			},
+			None => Self::atoi_iter(iter).map(Some),
+		}
+	}
+	
+	///Array parsing using an iterator and stopping on the 'X' character, the sentinels of the missing value are `None`.
+	///
+	///The iterator is cloned to look for the sentinels, a sentinel is consumed with the stop byte.
+	fn atoi_iter_stop_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		match atoi_null_len(iter, Some(end), nulls) {
+			Some(len) => {
+				let _ignore = iter.nth(len);
+				Ok(None)
+			},
+			None => Self::atoi_iter_stop(iter, end).map(Some),
+		}
+	}
+	
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred, the sentinels of the missing value are `None`.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, ATOI_NULLS};
+	///
+	///fn main() {
+	///	let array = b"12\t\\N\t\tx\t-\t7";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(i64::atoi_iter_wait_stop_null(&mut array_iter, b'\t', ATOI_NULLS), Ok(Some(12)));
+	///	assert_eq!(i64::atoi_iter_wait_stop_null(&mut array_iter, b'\t', ATOI_NULLS), Ok(None));
+	///	assert_eq!(i64::atoi_iter_wait_stop_null(&mut array_iter, b'\t', ATOI_NULLS), Ok(None));
+	///	assert_eq!(i64::atoi_iter_wait_stop_null(&mut array_iter, b'\t', ATOI_NULLS), Err(AtoiErr::ByteUnk(b'x')));
+	///	assert_eq!(i64::atoi_iter_wait_stop_null(&mut array_iter, b'\t', ATOI_NULLS), Ok(None));
+	///	assert_eq!(i64::atoi_iter_wait_stop_null(&mut array_iter, b'\t', ATOI_NULLS), Ok(Some(7)));
+	///}
+	///```
+	fn atoi_iter_wait_stop_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		match atoi_null_len(iter, Some(end), nulls) {
+			Some(len) => {
+				let _ignore = iter.nth(len);
+				Ok(None)
+			},
+			None => Self::atoi_iter_wait_stop(iter, end).map(Some),
+		}
+	}
+}
+
+impl<A: Atoi<T>, T> Atoi<T> for &A {
+	#[inline(always)]
+	fn atoi(array: &[u8]) -> AtoiResult<T> {
+		A::atoi(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_stop(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_wait_stop(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T> {
+		A::atoi_iter(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_stop(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_wait_stop(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_err<E: FromAtoiErr>(array: &[u8]) -> Result<T, E> {
+		A::atoi_err(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		A::atoi_stop_err(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		A::atoi_wait_stop_err(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, E> {
+		A::atoi_iter_err(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		A::atoi_iter_stop_err(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		A::atoi_iter_wait_stop_err(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_prefix(array: &[u8]) -> AtoiResult<(T, &[u8])> {
+		A::atoi_prefix(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_reason(array: &[u8], stop: u8) -> AtoiResult<(T, AtoiStop)> {
+		A::atoi_stop_reason(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_reason(array: &[u8], stop: u8) -> (AtoiResult<T>, AtoiStop) {
+		A::atoi_wait_stop_reason(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_reason<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, AtoiStop)> {
+		A::atoi_iter_stop_reason(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_reason<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> (AtoiResult<T>, AtoiStop) {
+		A::atoi_iter_wait_stop_reason(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_strict(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_stop_strict(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_strict(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_wait_stop_strict(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_strict<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_stop_strict(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_strict<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_wait_stop_strict(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I) -> AtoiResult<T> {
+		A::atoi_iter_try(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_stop_try(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_wait_stop_try(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_list(array: &[u8], stop: u8, max_errors: usize) -> AtoiList<T> {
+		A::atoi_list(array, stop, max_errors)
+	}
+	
+	#[inline(always)]
+	fn atoi_info(array: &[u8]) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_info(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_info(array: &[u8], stop: u8) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_stop_info(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_info<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_iter_info(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_info<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_iter_stop_info(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_range<R: RangeBounds<T>>(array: &[u8], range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_range(array, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_range<R: RangeBounds<T>>(array: &[u8], stop: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_stop_range(array, stop, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_range<R: RangeBounds<T>>(array: &[u8], stop: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_wait_stop_range(array, stop, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_iter_range(iter, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, end: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_iter_stop_range(iter, end, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, end: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_iter_wait_stop_range(iter, end, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_null(array: &[u8], nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_null(array, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_null(array: &[u8], stop: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_stop_null(array, stop, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_null(array: &[u8], stop: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_wait_stop_null(array, stop, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_iter_null(iter, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_iter_stop_null(iter, end, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_iter_wait_stop_null(iter, end, nulls)
+	}
+}
+impl<A: Atoi<T>, T> Atoi<T> for &mut A {
+	#[inline(always)]
+	fn atoi(array: &[u8]) -> AtoiResult<T> {
+		A::atoi(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_stop(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_wait_stop(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T> {
+		A::atoi_iter(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_stop(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_wait_stop(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_err<E: FromAtoiErr>(array: &[u8]) -> Result<T, E> {
+		A::atoi_err(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		A::atoi_stop_err(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		A::atoi_wait_stop_err(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, E> {
+		A::atoi_iter_err(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		A::atoi_iter_stop_err(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		A::atoi_iter_wait_stop_err(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_prefix(array: &[u8]) -> AtoiResult<(T, &[u8])> {
+		A::atoi_prefix(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_reason(array: &[u8], stop: u8) -> AtoiResult<(T, AtoiStop)> {
+		A::atoi_stop_reason(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_reason(array: &[u8], stop: u8) -> (AtoiResult<T>, AtoiStop) {
+		A::atoi_wait_stop_reason(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_reason<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, AtoiStop)> {
+		A::atoi_iter_stop_reason(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_reason<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> (AtoiResult<T>, AtoiStop) {
+		A::atoi_iter_wait_stop_reason(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_strict(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_stop_strict(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_strict(array: &[u8], stop: u8) -> AtoiResult<T> {
+		A::atoi_wait_stop_strict(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_strict<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_stop_strict(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_strict<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_wait_stop_strict(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I) -> AtoiResult<T> {
+		A::atoi_iter_try(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_stop_try(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_try<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8) -> AtoiResult<T> {
+		A::atoi_iter_wait_stop_try(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_list(array: &[u8], stop: u8, max_errors: usize) -> AtoiList<T> {
+		A::atoi_list(array, stop, max_errors)
+	}
+	
+	#[inline(always)]
+	fn atoi_info(array: &[u8]) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_info(array)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_info(array: &[u8], stop: u8) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_stop_info(array, stop)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_info<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_iter_info(iter)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_info<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, AtoiInfo)> {
+		A::atoi_iter_stop_info(iter, end)
+	}
+	
+	#[inline(always)]
+	fn atoi_range<R: RangeBounds<T>>(array: &[u8], range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_range(array, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_range<R: RangeBounds<T>>(array: &[u8], stop: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_stop_range(array, stop, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_range<R: RangeBounds<T>>(array: &[u8], stop: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_wait_stop_range(array, stop, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_iter_range(iter, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, end: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_iter_stop_range(iter, end, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_range<'a, I: Iterator<Item=&'a u8>, R: RangeBounds<T>>(iter: I, end: u8, range: R) -> AtoiResult<T> where T: PartialOrd + Into<AnyInt> {
+		A::atoi_iter_wait_stop_range(iter, end, range)
+	}
+	
+	#[inline(always)]
+	fn atoi_null(array: &[u8], nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_null(array, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_stop_null(array: &[u8], stop: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_stop_null(array, stop, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_wait_stop_null(array: &[u8], stop: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_wait_stop_null(array, stop, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_iter_null(iter, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_stop_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_iter_stop_null(iter, end, nulls)
+	}
+	
+	#[inline(always)]
+	fn atoi_iter_wait_stop_null<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &mut I, end: u8, nulls: &[&[u8]]) -> AtoiResult<Option<T>> {
+		A::atoi_iter_wait_stop_null(iter, end, nulls)
+	}
+}
+
+///Result trait Atoi
+#[derive(Debug, PartialEq, Clone)]
+pub enum AtoiErr {
+	///Overflow of number.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///assert_eq!(u8::atoi(b"256"), Result::Err(AtoiErr::Overflow));
+	///assert_eq!(u16::atoi(b"65536"), Result::Err(AtoiErr::Overflow));
+	///assert_eq!(u32::atoi(b"4294967296"), Result::Err(AtoiErr::Overflow));
+	///assert_eq!(u64::atoi(b"18446744073709551616"), Result::Err(AtoiErr::Overflow));
+	///```
+	Overflow,
+	
+	///In the byte sequence, an unknown character was used.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///assert_eq!(u8::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
+	///assert_eq!(u16::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
+	///assert_eq!(u32::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
+	///assert_eq!(u64::atoi(b"-128"), Result::Err(AtoiErr::ByteUnk(b'-')));
+	///
+	///assert_eq!(u8::atoi(b"128U"), Result::Err(AtoiErr::ByteUnk(b'U')));
+	///assert_eq!(u16::atoi(b"128L"), Result::Err(AtoiErr::ByteUnk(b'L')));
+	///assert_eq!(u32::atoi(b"128I"), Result::Err(AtoiErr::ByteUnk(b'I')));
+	///assert_eq!(u64::atoi(b"128N"), Result::Err(AtoiErr::ByteUnk(b'N')));
+	///```
+	///
+	ByteUnk(u8),
+	
+	///The byte sequence does not begin with a number.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///assert_eq!(u8::atoi_prefix(b""), Result::Err(AtoiErr::NoDigits));
+	///assert_eq!(i8::atoi_prefix(b"-"), Result::Err(AtoiErr::NoDigits));
+	///assert_eq!(u8::atoi_prefix(b"x1"), Result::Err(AtoiErr::NoDigits));
+	///```
+	///
+	NoDigits,
+	
+	///The input ended before the stop byte in the strict mode.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr};
+	///
+	///assert_eq!(u8::atoi_stop_strict(b"5", b':'), Result::Err(AtoiErr::MissingStop));
+	///assert_eq!(u8::atoi_stop_strict(b"", b':'), Result::Err(AtoiErr::MissingStop));
+	///```
+	///
+	MissingStop,
+	
+	///The number is zero, but the type does not allow it (`NonZero*`), or the denominator of the fraction is zero.
+	///
+	///```rust
+	///use std::num::NonZeroU32;
+	///use cluatoi::{Atoi, AtoiRatio, AtoiErr};
+	///
+	///assert_eq!(NonZeroU32::atoi(b"0"), Result::Err(AtoiErr::Zero));
+	///assert_eq!(NonZeroU32::atoi(b"000"), Result::Err(AtoiErr::Zero));
+	///assert_eq!(u32::atoi_ratio(b"1/0"), Result::Err(AtoiErr::Zero));
+	///```
+	///
+	Zero,
+	
+	///The number is out of the range of values, the number itself.
+	///
+	///```rust
+	///use cluatoi::{Atoi, AtoiErr, AnyInt};
+	///
+	///assert_eq!(u8::atoi_range(b"13", 1..=12), Result::Err(AtoiErr::OutOfRange(AnyInt::U8(13))));
+	///assert_eq!(i32::atoi_range(b"-1", 0..), Result::Err(AtoiErr::OutOfRange(AnyInt::I32(-1))));
+	///```
+	///
+	OutOfRange(AnyInt),
+	
+	///The exponent is missing: the hexadecimal float without `p`, or no digits after `p`/`e`.
+	///
+	///```rust
+	///use cluatoi::{Atof, AtoiSci, AtoiErr};
+	///
+	///assert_eq!(f64::atof_hex(b"0x1.8"), Result::Err(AtoiErr::MissingExponent));
+	///assert_eq!(f64::atof_hex(b"0x1.8p"), Result::Err(AtoiErr::MissingExponent));
+	///assert_eq!(u32::atoi_sci(b"1e+"), Result::Err(AtoiErr::MissingExponent));
+	///```
+	///
+	MissingExponent,
+	
+	///The number cannot be represented exactly, the discarded digits are not zero.
+	///
+	///```rust
+	///use cluatoi::{AtoiFixed, AtoiExcess, AtoiSci, AtoiErr};
+	///
+	///assert_eq!(u32::atoi_fixed(b"0.125", 2, AtoiExcess::Reject), Result::Err(AtoiErr::Inexact));
+	///assert_eq!(u32::atoi_sci(b"2.5"), Result::Err(AtoiErr::Inexact));
+	///```
+	///
+	Inexact,
+}
+
+
+///Conversion of AtoiErr into the error type of the caller.
+pub trait FromAtoiErr {
+	///`pos` is the position of the erroneous byte from the beginning of the parsed sequence (`0` for errors of the whole number, such as `AtoiErr::Zero`).
+	fn from_atoi_err(err: AtoiErr, pos: usize) -> Self;
+}
+
+impl FromAtoiErr for AtoiErr {
+	#[inline(always)]
+	fn from_atoi_err(err: AtoiErr, _pos: usize) -> Self {
+		err
+	}
+}
+
+impl FromAtoiErr for (AtoiErr, usize) {
+	#[inline(always)]
+	fn from_atoi_err(err: AtoiErr, pos: usize) -> Self {
+		(err, pos)
+	}
+}
+
+///Reason of the stop of parsing.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AtoiStop {
+	///The stop byte was found, its position from the beginning of the iterator.
+	Byte(usize),
+	
+	///The input ended before the stop byte.
+	End,
+}
+
+//The number if it is in the range, otherwise AtoiErr::OutOfRange.
+#[inline]
+fn atoi_range_check<T: PartialOrd + Into<AnyInt>, R: RangeBounds<T>>(num: T, range: R) -> AtoiResult<T> {
+	match range.contains(&num) {
+		true => Ok(num),
+		false => Err(AtoiErr::OutOfRange(num.into())),
+	}
+}
+
+///Common sentinels of the missing value: empty field, `NULL`, `\N` (database dumps), `-` (Apache logs).
+pub const ATOI_NULLS: &[&[u8]] = &[b"", b"NULL", b"\\N", b"-"];
+
+//Length of the sentinel if the field up to the stop byte (or the end) is one of them.
+fn atoi_null_len<'a, I: Iterator<Item=&'a u8> + Clone>(iter: &I, end: Option<u8>, nulls: &[&[u8]]) -> Option<usize> {
+	nulls.iter().find(|null| {
+		let mut iter = iter.clone();
+		null.iter().all(|a| iter.next() == Some(a)) && match iter.next() {
+			Some(a) => Some(*a) == end,
+			None => true,
+		}
+	}).map(|null| null.len())
+}
+
+//Iterator counting the bytes passed to the parser.
+struct AtoiTrack<I> {
+	iter: I,
+	count: usize,
+	ended: bool,
+}
+
+impl<I> AtoiTrack<I> {
+	#[inline(always)]
+	fn new(iter: I) -> Self {
+		AtoiTrack {
+			iter,
+			count: 0,
+			ended: false,
+		}
+	}
+	
+	//The caller's error, the byte errors are on the last byte read.
+	#[inline]
+	fn err<E: FromAtoiErr>(&self, err: AtoiErr) -> E {
+		let pos = match err {
+			AtoiErr::ByteUnk(_) | AtoiErr::Overflow => self.count.saturating_sub(1),
+			_ => 0,
+		};
+		E::from_atoi_err(err, pos)
+	}
+	
+	//The parser stops either on the stop byte (the last byte read) or at the end of the input,
+	//a parser that has not read anything did not reach a stop byte.
+	#[inline]
+	fn stop(&self) -> AtoiStop {
+		match (self.ended, self.count.checked_sub(1)) {
+			(false, Some(pos)) => AtoiStop::Byte(pos),
+			_ => AtoiStop::End,
+		}
+	}
+}
+
+impl<'a, I: Iterator<Item=&'a u8>> Iterator for AtoiTrack<I> {
+	type Item = &'a u8;
+	
+	#[inline]
+	fn next(&mut self) -> Option<&'a u8> {
+		match self.iter.next() {
+			Some(a) => {
+				self.count += 1;
+				Some(a)
+			},
+			None => {
+				self.ended = true;
+				None
+			},
+		}
+	}
+}
+
+//Skipping to the stop byte after an error of the wait_stop parsers.
+#[inline]
+fn atoi_skip_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: Option<u8>, wait: bool) {
+	if let (Some(end), true) = (end, wait) {
+		for a in iter {
+			if *a == end {
+				break;
+			}
+		}
+	}
+}
+
+//Decimal exponent of atof, atof_hex and atoi_sci. Saturated at i64::MAX, the digits of the number
+//can shift it back only by their count.
+#[derive(Default)]
+struct AtoiExp {
+	value: i64,
+	negative: bool,
+}
+
+impl AtoiExp {
+	#[inline]
+	fn push_digit(&mut self, digit: u8) {
+		self.value = self.value.saturating_mul(10).saturating_add(digit as i64);
+	}
+	
+	//shift +- exponent
+	#[inline]
+	fn add_to(&self, shift: i64) -> i64 {
+		shift.saturating_add(match self.negative {
+			true => -self.value,
+			false => self.value,
+		})
+	}
+}
+
+///Result of Atoi::atoi_list.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AtoiList<T> {
+	///Successfully parsed values, in order.
+	pub values: Vec<T>,
+	
+	///Collected errors, in order.
+	pub errors: Vec<AtoiListErr>,
+	
+	///Number of errors not collected because of the limit.
+	pub skipped_errors: usize,
+}
+
+///Error of one field of Atoi::atoi_list.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AtoiListErr {
+	///Index of the field.
+	pub field: usize,
+	
+	///Offset of the failing byte in the array, the beginning of the field if the error is not caused by one byte.
+	pub offset: usize,
+	
+	///Error of the field.
+	pub err: AtoiErr,
+}
+
+///Information about the notation of the parsed number.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AtoiInfo {
+	///Sign byte (`b'+'` or `b'-'`), if present.
+	pub sign: Option<u8>,
+	
+	///Number of zeros before the first significant digit (`0` has none, `000` has two).
+	pub leading_zeros: usize,
+	
+	///Number of digits, including leading zeros.
+	pub digits: usize,
+	
+	///Radix of the notation.
+	pub radix: u32,
+	
+	///Number of bytes of the number, without the stop byte.
+	pub consumed: usize,
+}
+
+//Iterator collecting AtoiInfo from the bytes passed to the parser.
+struct AtoiInfoTrack<I> {
+	iter: I,
+	end: Option<u8>,
+	info: AtoiInfo,
+	significant: bool,
+}
+
+impl<I> AtoiInfoTrack<I> {
+	#[inline(always)]
+	fn new(iter: I, end: Option<u8>) -> Self {
+		AtoiInfoTrack {
+			iter,
+			end,
+			info: AtoiInfo {
+				sign: None,
+				leading_zeros: 0,
+				digits: 0,
+				radix: 10,
+				consumed: 0,
+			},
+			significant: false,
+		}
+	}
+	
+	#[inline]
+	fn info(&self) -> AtoiInfo {
+		let mut info = self.info;
+		if !self.significant && info.digits != 0 {
+			//the last zero is the number itself
+			info.leading_zeros = info.digits - 1;
+		}
+		
+		info
+	}
+}
+
+impl<'a, I: Iterator<Item=&'a u8>> Iterator for AtoiInfoTrack<I> {
+	type Item = &'a u8;
+	
+	fn next(&mut self) -> Option<&'a u8> {
+		let a = self.iter.next()?;
+		if Some(*a) == self.end {
+			return Some(a);
+		}
+		
+		match *a {
+			b'+' | b'-' if self.info.consumed == 0 => self.info.sign = Some(*a),
+			b'0' => {
+				self.info.digits += 1;
+				if !self.significant {
+					self.info.leading_zeros += 1;
+				}
+			},
+			b'1' ..= b'9' => {
+				self.info.digits += 1;
+				self.significant = true;
+			},
+			_ => {},
+		}
+		self.info.consumed += 1;
+		
+		Some(a)
+	}
+}
+
+///Type result AtoiPartial.
+pub type AtoiPartialResult<T> = Result<T, AtoiPartialErr<T>>;
+
+///Error trait AtoiPartial, the value accumulated before the error.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AtoiPartialErr<T> {
+	///Cause of the stop.
+	pub err: AtoiErr,
+
+	///The value accumulated before the erroneous byte (for `Overflow`, before the digit that did not fit).
+	pub value: T,
+
+	///Number of digits included in `value`.
+	pub digits: usize,
+}
+
+///Parsing like C `strtol`: on error, the value accumulated so far is returned together with the error.
+pub trait AtoiPartial<T> {
+	///Array parsing with the partial value on error.
+	///
+	///```rust
+	///use cluatoi::{AtoiPartial, AtoiPartialErr, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u8::atoi_partial(b"25x"), Err(AtoiPartialErr {
+	///		err: AtoiErr::ByteUnk(b'x'),
+	///		value: 25,
+	///		digits: 2,
+	///	}));
+	///
+	///	//256 does not fit u8, 25 was accumulated.
+	///	assert_eq!(u8::atoi_partial(b"2560"), Err(AtoiPartialErr {
+	///		err: AtoiErr::Overflow,
+	///		value: 25,
+	///		digits: 2,
+	///	}));
+	///}
+	///```
+	#[inline]
+	fn atoi_partial(array: &[u8]) -> AtoiPartialResult<T> {
+		Self::atoi_iter_partial(array.iter())
+	}
+
+	///Array parsing using an iterator with the partial value on error.
+	///
+	///```rust
+	///use cluatoi::AtoiPartial;
+	///
+	///fn main() {
+	///	let array = b"-12!";
+	///	let mut array_iter = array.iter();
+	///
+	///	let err = i32::atoi_iter_partial(&mut array_iter).unwrap_err();
+	///	assert_eq!(err.value, -12);
+	///	assert_eq!(err.digits, 2);
+	///
+	///	assert_eq!(array_iter.next(), None);
+	///}
+	///```
+	fn atoi_iter_partial<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiPartialResult<T>;
+}
+
+
+///Behaviour of AtoiPolicy on overflow of number.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AtoiOverflow {
+	///Stop with `AtoiErr::Overflow`, as Atoi does.
+	Error,
+	
+	///Clamp to `T::MAX` (`T::MIN` for negative numbers).
+	Saturate,
+	
+	///Wrap modulo 2^N.
+	Wrap,
+}
+
+///Parsing with the selectable behaviour on overflow of number.
+///
+///The value is returned with the overflow flag, `true` if the value was clamped or wrapped.
+///
+///Implemented for every `AtoiNum` type, the bounds and the wrapping are `AtoiNum::saturating_bound` and `AtoiNum::wrapping_push_digit`,
+///for the NonZero types (a clamped or wrapped zero is `AtoiErr::Zero`) and for `BigInt` (never overflows).
+pub trait AtoiPolicy<T> {
+	///Array parsing with the behaviour on overflow.
+	///
+	///```rust
+	///use cluatoi::{AtoiPolicy, AtoiOverflow, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u8::atoi_policy(b"300", AtoiOverflow::Error), Err(AtoiErr::Overflow));
+	///	assert_eq!(u8::atoi_policy(b"300", AtoiOverflow::Saturate), Ok((255, true)));
+	///	assert_eq!(u8::atoi_policy(b"300", AtoiOverflow::Wrap), Ok((44, true)));
+	///
+	///	assert_eq!(i8::atoi_policy(b"-300", AtoiOverflow::Saturate), Ok((-128, true)));
+	///	assert_eq!(u8::atoi_policy(b"200", AtoiOverflow::Saturate), Ok((200, false)));
+	///}
+	///```
+	#[inline]
+	fn atoi_policy(array: &[u8], policy: AtoiOverflow) -> AtoiResult<(T, bool)> {
+		Self::atoi_iter_policy(array.iter(), policy)
+	}
+	
+	///Array parsing and stopping on the 'X' character with the behaviour on overflow.
+	#[inline]
+	fn atoi_stop_policy(array: &[u8], stop: u8, policy: AtoiOverflow) -> AtoiResult<(T, bool)> {
+		Self::atoi_iter_stop_policy(array.iter(), stop, policy)
+	}
+	
+	///An array analysis waiting for an "X" character even if an error occurred with the behaviour on overflow.
+	#[inline]
+	fn atoi_wait_stop_policy(array: &[u8], stop: u8, policy: AtoiOverflow) -> AtoiResult<(T, bool)> {
+		Self::atoi_iter_wait_stop_policy(array.iter(), stop, policy)
+	}
+	
+	///Array parsing using an iterator with the behaviour on overflow.
+	fn atoi_iter_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, policy: AtoiOverflow) -> AtoiResult<(T, bool)>;
+	
+	///Array parsing using an iterator and stopping on the 'X' character with the behaviour on overflow.
+	///
+	///```rust
+	///use cluatoi::{AtoiPolicy, AtoiOverflow};
+	///
+	///fn main() {
+	///	let array = b"70000!1";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u16::atoi_iter_stop_policy(&mut array_iter, b'!', AtoiOverflow::Saturate), Ok((65535, true)));
+	///	assert_eq!(array_iter.next(), Some(&b'1'));
+	///}
+	///```
+	fn atoi_iter_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> AtoiResult<(T, bool)>;
+	
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred with the behaviour on overflow.
+	fn atoi_iter_wait_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> AtoiResult<(T, bool)>;
+	
+	///Array parsing with the behaviour on overflow and the error type of the caller.
+	///
+	///```rust
+	///use cluatoi::{AtoiPolicy, AtoiOverflow, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u8::atoi_policy_err::<(AtoiErr, usize)>(b"300x", AtoiOverflow::Wrap), Err((AtoiErr::ByteUnk(b'x'), 3)));
+	///	assert_eq!(u8::atoi_policy_err::<(AtoiErr, usize)>(b"300", AtoiOverflow::Error), Err((AtoiErr::Overflow, 2)));
+	///}
+	///```
+	#[inline]
+	fn atoi_policy_err<E: FromAtoiErr>(array: &[u8], policy: AtoiOverflow) -> Result<(T, bool), E> {
+		Self::atoi_iter_policy_err(array.iter(), policy)
+	}
+	
+	///Array parsing and stopping on the 'X' character with the behaviour on overflow and the error type of the caller.
+	#[inline]
+	fn atoi_stop_policy_err<E: FromAtoiErr>(array: &[u8], stop: u8, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		Self::atoi_iter_stop_policy_err(array.iter(), stop, policy)
+	}
+	
+	///An array analysis waiting for an "X" character even if an error occurred with the behaviour on overflow and the error type of the caller.
+	#[inline]
+	fn atoi_wait_stop_policy_err<E: FromAtoiErr>(array: &[u8], stop: u8, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		Self::atoi_iter_wait_stop_policy_err(array.iter(), stop, policy)
+	}
+	
+	///Array parsing using an iterator with the behaviour on overflow and the error type of the caller.
+	///
+	///By default the position is found as in `Atoi::atoi_iter_err`.
+	fn atoi_iter_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		let mut iter = AtoiTrack::new(iter);
+		Self::atoi_iter_policy(&mut iter, policy).map_err(|err| iter.err(err))
+	}
+	
+	///Array parsing using an iterator and stopping on the 'X' character with the behaviour on overflow and the error type of the caller.
+	fn atoi_iter_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		let mut iter = AtoiTrack::new(iter);
+		Self::atoi_iter_stop_policy(&mut iter, end, policy).map_err(|err| iter.err(err))
+	}
+	
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred with the behaviour on overflow and the error type of the caller.
+	///
+	///By default the number is parsed by `atoi_iter_stop_policy` as in `Atoi::atoi_iter_wait_stop_err`.
+	fn atoi_iter_wait_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		let mut iter = AtoiTrack::new(iter);
+		Self::atoi_iter_stop_policy(&mut iter, end, policy).map_err(|err| match err {
+			AtoiErr::ByteUnk(_) | AtoiErr::Overflow => {
+				let err = iter.err(err);
+				atoi_skip_stop(iter.by_ref(), Some(end), true);
+				err
+			},
+			_ => iter.err(err),
+		})
+	}
+}
+
+//Parsing of AtoiNum with the behaviour on overflow.
+fn atoi_num_policy<'a, T, E, I>(mut iter: I, end: Option<u8>, wait: bool, policy: AtoiOverflow) -> Result<(T, bool), E>
+	where T: AtoiNum, E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let mut result = T::ZERO;
+	let mut negative = false;
+	let mut overflow = false;
+	let mut pos = 0;
+	
+	let err = loop {
+		let a = match iter.next() {
+			Some(a) => *a,
+			None => break None,
+		};
+		match a {
+			b'-' | b'+' if pos == 0 && T::SIGNED => negative = a == b'-',
+			_ if Some(a) == end => break None,
+			b'0' ..= b'9' => {
+				let digit = a - b'0';
+				let next = result.checked_mul_radix(10).and_then(|r| match negative {
+					true => r.checked_sub_digit(digit),
+					false => r.checked_add_digit(digit),
+				});
+				let next = match (next, policy) {
+					(Some(r), _) => Some(r),
+					(None, AtoiOverflow::Error) => None,
+					(None, AtoiOverflow::Saturate) => {
+						overflow = true;
+						T::saturating_bound(negative)
+					},
+					(None, AtoiOverflow::Wrap) => {
+						overflow = true;
+						result.wrapping_push_digit(10, digit, negative)
+					},
+				};
+				result = match next {
+					Some(r) => r,
+					None => break Some(AtoiErr::Overflow),
+				};
+			},
+			_ => break Some(AtoiErr::ByteUnk(a)),
+		}
+		pos += 1;
+	};
+	
+	match err {
+		None => Ok((result, overflow)),
+		Some(err) => {
+			atoi_skip_stop(iter, end, wait);
+			Err(E::from_atoi_err(err, pos))
+		},
+	}
+}
+
+impl<T: AtoiNum> AtoiPolicy<T> for T {
+	#[inline]
+	fn atoi_iter_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, policy: AtoiOverflow) -> AtoiResult<(T, bool)> {
+		atoi_num_policy(iter, None, false, policy)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> AtoiResult<(T, bool)> {
+		atoi_num_policy(iter, Some(end), false, policy)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> AtoiResult<(T, bool)> {
+		atoi_num_policy(iter, Some(end), true, policy)
+	}
+	
+	#[inline]
+	fn atoi_iter_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		atoi_num_policy(iter, None, false, policy)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		atoi_num_policy(iter, Some(end), false, policy)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> Result<(T, bool), E> {
+		atoi_num_policy(iter, Some(end), true, policy)
+	}
 }
 
 
@@ -358,9 +1961,11 @@ macro_rules! atoi_build {
 	
 	//UNSIGNED ITERATION
 	(unsigned, $iter:expr) => {{
-		let mut result: Self = 0;
+		let mut result = Self::ZERO;
+		let mut pos = 0;
 		while let Some(a) = $iter.next() {
-			atoi_build_fn!(+, *a, result);
+			atoi_build_fn!(+, *a, result, pos);
+			pos += 1;
 		}
 		
 		return Ok(result);
@@ -368,9 +1973,11 @@ macro_rules! atoi_build {
 	
 	//UNSIGNED ITERATION + STOP END CHAR
 	(unsigned, $iter:expr, $end:expr) => {{
-		let mut result: Self = 0;
+		let mut result = Self::ZERO;
+		let mut pos = 0;
 		while let Some(a) = $iter.next() {
-			atoi_build_fn!(+, *a, result, $end);
+			atoi_build_fn!(+, *a, result, pos, $end);
+			pos += 1;
 		}
 		
 		return Ok(result);
@@ -378,9 +1985,11 @@ macro_rules! atoi_build {
 	
 	//UNSIGNED ITERATION + WAIT END CHAR
 	(unsigned_wait_end, $iter:expr, $end:expr) => {{
-		let mut result: Self = 0;
+		let mut result = Self::ZERO;
+		let mut pos = 0;
 		while let Some(a) = $iter.next() {
-			atoi_build_fn!(+wait_end, *a, result, $iter, $end);
+			atoi_build_fn!(+wait_end, *a, result, pos, $iter, $end);
+			pos += 1;
 		}
 		
 		return Ok(result);
@@ -388,25 +1997,29 @@ macro_rules! atoi_build {
 	
 	//SIGNED ITERATION
 	(signed, $iter:expr) => {{
-		let mut result: Self = 0;
+		let mut result = Self::ZERO;
+		let mut pos = 1;
 		if let Some(a) = $iter.next() {
 			match *a {
 				b'-' => {
 					//NEGATIVE
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(-, *a, result);
+						atoi_build_fn!(-, *a, result, pos);
+						pos += 1;
 					}
 				},
 				b'+' => {
 					//POSITIVE
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(+, *a, result);
+						atoi_build_fn!(+, *a, result, pos);
+						pos += 1;
 					}
 				},
 				a => {
-					atoi_build_fn!(+, a, result);
+					atoi_build_fn!(+, a, result, 0);
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(+, *a, result);
+						atoi_build_fn!(+, *a, result, pos);
+						pos += 1;
 					}
 				}
 			}
@@ -416,25 +2029,29 @@ macro_rules! atoi_build {
 	
 	//SIGNED ITERATION + STOP END CHAR
 	(signed, $iter:expr, $end:expr) => {{
-		let mut result: Self = 0;
+		let mut result = Self::ZERO;
+		let mut pos = 1;
 		if let Some(a) = $iter.next() {
 			match *a {
 				b'-' => {
 					//NEGATIVE
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(-, *a, result, $end);
+						atoi_build_fn!(-, *a, result, pos, $end);
+						pos += 1;
 					}
 				},
 				b'+' => {
 					//POSITIVE
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(+, *a, result, $end);
+						atoi_build_fn!(+, *a, result, pos, $end);
+						pos += 1;
 					}
 				},
 				a => {
-					atoi_build_fn!(+, a, result, $end);
+					atoi_build_fn!(+, a, result, 0, $end);
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(+, *a, result, $end);
+						atoi_build_fn!(+, *a, result, pos, $end);
+						pos += 1;
 					}
 				}
 			}
@@ -444,25 +2061,69 @@ macro_rules! atoi_build {
 	
 	//SIGNED ITERATION + WAIT END CHAR
 	(signed_wait_end, $iter:expr, $end:expr) => {{
-		let mut result: Self = 0;
+		let mut result = Self::ZERO;
+		let mut pos = 1;
+		if let Some(a) = $iter.next() {
+			match *a {
+				b'-' => {
+					//NEGATIVE
+					while let Some(a) = $iter.next() {
+						atoi_build_fn!(-wait_end, *a, result, pos, $iter, $end);
+						pos += 1;
+					}
+				},
+				b'+' => {
+					//POSITIVE
+					while let Some(a) = $iter.next() {
+						atoi_build_fn!(+wait_end, *a, result, pos, $iter, $end);
+						pos += 1;
+					}
+				},
+				a => {
+					atoi_build_fn!(+wait_end, a, result, 0, $iter, $end);
+					while let Some(a) = $iter.next() {
+						atoi_build_fn!(+wait_end, *a, result, pos, $iter, $end);
+						pos += 1;
+					}
+				}
+			}
+		}
+		return Ok(result);
+	}};
+	
+	//UNSIGNED ITERATION + PARTIAL VALUE
+	(unsigned_partial, $iter:expr) => {{
+		let mut result = Self::ZERO;
+		let mut digits = 0;
+		while let Some(a) = $iter.next() {
+			atoi_build_fn!(+partial, *a, result, digits);
+		}
+		
+		return Ok(result);
+	}};
+	
+	//SIGNED ITERATION + PARTIAL VALUE
+	(signed_partial, $iter:expr) => {{
+		let mut result = Self::ZERO;
+		let mut digits = 0;
 		if let Some(a) = $iter.next() {
 			match *a {
 				b'-' => {
 					//NEGATIVE
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(-wait_end, *a, result, $iter, $end);
+						atoi_build_fn!(-partial, *a, result, digits);
 					}
 				},
 				b'+' => {
 					//POSITIVE
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(+wait_end, *a, result, $iter, $end);
+						atoi_build_fn!(+partial, *a, result, digits);
 					}
 				},
 				a => {
-					atoi_build_fn!(+wait_end, a, result, $iter, $end);
+					atoi_build_fn!(+partial, a, result, digits);
 					while let Some(a) = $iter.next() {
-						atoi_build_fn!(+wait_end, *a, result, $iter, $end);
+						atoi_build_fn!(+partial, *a, result, digits);
 					}
 				}
 			}
@@ -474,42 +2135,42 @@ macro_rules! atoi_build {
 ///Signed Atoi Macros
 macro_rules! atoi_build_fn {
 	//ADD FN
-	(+, $a:expr, $result:expr) => {
-		if !($a >= b'0' && $a <= b'9') {
-			return Err(AtoiErr::ByteUnk($a));
+	(+, $a:expr, $result:expr, $pos:expr) => {
+		if !$a.is_ascii_digit() {
+			return Err(E::from_atoi_err(AtoiErr::ByteUnk($a), $pos));
 		}
 		
-		match $result.checked_mul(10) {
+		match $result.checked_mul_radix(10) {
 			Some(s) => {
-				match s.checked_add(($a - b'0') as Self) {
+				match s.checked_add_digit($a - b'0') {
 					Some(s) => {
 						$result = s;
 					},
 					_ => {
-						return Err(AtoiErr::Overflow);
+						return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
 					},
 				}
 			},
 			_ => {
-				return Err(AtoiErr::Overflow);
+				return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
 			},
 		}
 	};
 	
 	//ADD FN + END CHAR
-	(+, $a:expr, $result:expr, $end:expr) => {
+	(+, $a:expr, $result:expr, $pos:expr, $end:expr) => {
 		if $end == $a {
 			return Ok($result);
 		}
-		atoi_build_fn!(+, $a, $result);
+		atoi_build_fn!(+, $a, $result, $pos);
 	};
 	
 	//ADD FN + END CHAR + WHILE DO END POS
-	(+wait_end, $a:expr, $result:expr, $iter:expr, $end:expr) => {
+	(+wait_end, $a:expr, $result:expr, $pos:expr, $iter:expr, $end:expr) => {
 		if $end == $a {
 			return Ok($result);
 		}
-		if !($a >= b'0' && $a <= b'9') {
+		if !$a.is_ascii_digit() {
 			//WHAT?
 			//
 			//let array = b"-10a0";
@@ -524,11 +2185,11 @@ macro_rules! atoi_build_fn {
 					break;
 				}
 			}
-			return Err(AtoiErr::ByteUnk($a));
+			return Err(E::from_atoi_err(AtoiErr::ByteUnk($a), $pos));
 		}
-		match $result.checked_mul(10) {
+		match $result.checked_mul_radix(10) {
 			Some(s) => {
-				match s.checked_add(($a - b'0') as Self) {
+				match s.checked_add_digit($a - b'0') {
 					Some(s) => {
 						$result = s;
 						//STOP THIS.
@@ -548,7 +2209,7 @@ macro_rules! atoi_build_fn {
 								break;
 							}
 						}
-						return Err(AtoiErr::Overflow);
+						return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
 					},
 				}
 			},
@@ -567,49 +2228,85 @@ macro_rules! atoi_build_fn {
 						break;
 					}
 				}
-				return Err(AtoiErr::Overflow);
+				return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
+			},
+		}
+	};
+	
+	//ADD FN + PARTIAL VALUE
+	(+partial, $a:expr, $result:expr, $digits:expr) => {
+		if !$a.is_ascii_digit() {
+			return Err(AtoiPartialErr {
+				err: AtoiErr::ByteUnk($a),
+				value: $result,
+				digits: $digits,
+			});
+		}
+		
+		match $result.checked_mul_radix(10) {
+			Some(s) => {
+				match s.checked_add_digit($a - b'0') {
+					Some(s) => {
+						$result = s;
+						$digits += 1;
+					},
+					_ => {
+						return Err(AtoiPartialErr {
+							err: AtoiErr::Overflow,
+							value: $result,
+							digits: $digits,
+						});
+					},
+				}
+			},
+			_ => {
+				return Err(AtoiPartialErr {
+					err: AtoiErr::Overflow,
+					value: $result,
+					digits: $digits,
+				});
 			},
 		}
 	};
 	
 	
 	//SUB FN
-	(-, $a:expr, $result:expr) => {
-		if !($a >= b'0' && $a <= b'9') {
-			return Err(AtoiErr::ByteUnk($a));
+	(-, $a:expr, $result:expr, $pos:expr) => {
+		if !$a.is_ascii_digit() {
+			return Err(E::from_atoi_err(AtoiErr::ByteUnk($a), $pos));
 		}
 		
-		match $result.checked_mul(10) {
+		match $result.checked_mul_radix(10) {
 			Some(s) => {
-				match s.checked_sub(($a - b'0') as Self) {
+				match s.checked_sub_digit($a - b'0') {
 					Some(s) => {
 						$result = s;
 					},
 					_ => {
-						return Err(AtoiErr::Overflow);
+						return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
 					},
 				}
 			},
 			_ => {
-				return Err(AtoiErr::Overflow);
+				return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
 			},
 		}
 	};
 	
 	//SUB FN + END CHAR
-	(-, $a:expr, $result:expr, $end:expr) => {
+	(-, $a:expr, $result:expr, $pos:expr, $end:expr) => {
 		if $end == $a {
 			return Ok($result);
 		}
-		atoi_build_fn!(-, $a, $result);
+		atoi_build_fn!(-, $a, $result, $pos);
 	};
 	
 	//SUB FN + END CHAR + WHILE DO END POS
-	(-wait_end, $a:expr, $result:expr, $iter:expr, $end:expr) => {
+	(-wait_end, $a:expr, $result:expr, $pos:expr, $iter:expr, $end:expr) => {
 		if $end == $a {
 			return Ok($result);
 		}
-		if !($a >= b'0' && $a <= b'9') {
+		if !$a.is_ascii_digit() {
 			//WHAT?
 			//
 			//let array = b"-10a0";
@@ -624,11 +2321,11 @@ macro_rules! atoi_build_fn {
 					break;
 				}
 			}
-			return Err(AtoiErr::ByteUnk($a));
+			return Err(E::from_atoi_err(AtoiErr::ByteUnk($a), $pos));
 		}
-		match $result.checked_mul(10) {
+		match $result.checked_mul_radix(10) {
 			Some(s) => {
-				match s.checked_sub(($a - b'0') as Self) {
+				match s.checked_sub_digit($a - b'0') {
 					Some(s) => {
 						$result = s;
 						//STOP THIS.
@@ -648,7 +2345,7 @@ macro_rules! atoi_build_fn {
 								break;
 							}
 						}
-						return Err(AtoiErr::Overflow);
+						return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
 					},
 				}
 			},
@@ -667,7 +2364,43 @@ macro_rules! atoi_build_fn {
 						break;
 					}
 				}
-				return Err(AtoiErr::Overflow);
+				return Err(E::from_atoi_err(AtoiErr::Overflow, $pos));
+			},
+		}
+	};
+	
+	//SUB FN + PARTIAL VALUE
+	(-partial, $a:expr, $result:expr, $digits:expr) => {
+		if !$a.is_ascii_digit() {
+			return Err(AtoiPartialErr {
+				err: AtoiErr::ByteUnk($a),
+				value: $result,
+				digits: $digits,
+			});
+		}
+		
+		match $result.checked_mul_radix(10) {
+			Some(s) => {
+				match s.checked_sub_digit($a - b'0') {
+					Some(s) => {
+						$result = s;
+						$digits += 1;
+					},
+					_ => {
+						return Err(AtoiPartialErr {
+							err: AtoiErr::Overflow,
+							value: $result,
+							digits: $digits,
+						});
+					},
+				}
+			},
+			_ => {
+				return Err(AtoiPartialErr {
+					err: AtoiErr::Overflow,
+					value: $result,
+					digits: $digits,
+				});
 			},
 		}
 	};
@@ -678,37 +2411,204 @@ macro_rules! atoi_build_fn {
 
 
 
+///Integer type for which `AtoiPartial` is implemented automatically and `Atoi` by the `atoi_build_num!` macro.
+///
+///Implementing it for fixed-point types, wide integers from other crates or newtypes gives the full parsing behaviour of the primitive integers, including the stop and wait-stop variants.
+///
+///```rust
+///#[macro_use]
+///extern crate cluatoi;
+///use cluatoi::Atoi;
+///use cluatoi::AtoiNum;
+///use cluatoi::AtoiErr;
+///
+///#[derive(Debug, PartialEq)]
+///struct Cents(u32);
+///
+///impl AtoiNum for Cents {
+///	const ZERO: Self = Cents(0);
+///	const SIGNED: bool = false;
+///
+///	fn checked_mul_radix(&self, radix: u8) -> Option<Self> {
+///		self.0.checked_mul(radix as u32).map(Cents)
+///	}
+///	fn checked_add_digit(&self, digit: u8) -> Option<Self> {
+///		self.0.checked_add(digit as u32).map(Cents)
+///	}
+///	fn checked_sub_digit(&self, digit: u8) -> Option<Self> {
+///		self.0.checked_sub(digit as u32).map(Cents)
+///	}
+///}
+///atoi_build_num!(Cents);
+///
+///fn main() {
+///	assert_eq!(Cents::atoi(b"1250"), Ok(Cents(1250)));
+///	assert_eq!(Cents::atoi_stop(b"99;1", b';'), Ok(Cents(99)));
+///	assert_eq!(Cents::atoi(b"-1"), Err(AtoiErr::ByteUnk(b'-')));
+///}
+///```
+pub trait AtoiNum: Sized {
+	///Value of the empty accumulator.
+	const ZERO: Self;
+	
+	///`true` if the type accepts the `+` and `-` signs.
+	const SIGNED: bool;
+	
+	///Multiplication by the radix, `None` on overflow.
+	fn checked_mul_radix(&self, radix: u8) -> Option<Self>;
+	
+	///Addition of a digit (positive numbers), `None` on overflow.
+	fn checked_add_digit(&self, digit: u8) -> Option<Self>;
+	
+	///Subtraction of a digit (negative numbers), `None` on overflow.
+	fn checked_sub_digit(&self, digit: u8) -> Option<Self>;
+	
+	///Largest value (the smallest one for negative numbers) used by `AtoiOverflow::Saturate`, `None` if the type has no bounds and the overflow is an error.
+	#[inline]
+	fn saturating_bound(_negative: bool) -> Option<Self> {
+		None
+	}
+	
+	///Multiplication by the radix and addition (subtraction for negative numbers) of a digit modulo the width of the type
+	///used by `AtoiOverflow::Wrap`, `None` if the type does not wrap and the overflow is an error.
+	#[inline]
+	fn wrapping_push_digit(&self, _radix: u8, _digit: u8, _negative: bool) -> Option<Self> {
+		None
+	}
+	
+	///Greatest common divisor of the value and a positive `denominator`, used by `AtoiRatio` to reduce the fraction.
+	///`None` if the type has no division, its fractions are then `AtoiErr::Overflow`.
+	#[inline]
+	fn checked_gcd(&self, _denominator: &Self) -> Option<Self> {
+		None
+	}
+	
+	///Division by a divisor of the value (see `checked_gcd`), `None` on overflow or if the type has no division.
+	#[inline]
+	fn checked_div_exact(&self, _divisor: &Self) -> Option<Self> {
+		None
+	}
+	
+	///`self * mul + add` for the whole part of a mixed number, `None` on overflow or if the type has no multiplication.
+	#[inline]
+	fn checked_mul_add(&self, _mul: &Self, _add: &Self) -> Option<Self> {
+		None
+	}
+	
+	///Parsing used by `Atoi::atoi_iter_err`, types with a faster algorithm can override it.
+	fn atoi_num_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(mut iter: I) -> Result<Self, E> {
+		match Self::SIGNED {
+			true => atoi_build!(signed, iter),
+			false => atoi_build!(unsigned, iter),
+		}
+	}
+	
+	///Parsing used by `Atoi::atoi_iter_stop_err`, types with a faster algorithm can override it.
+	fn atoi_num_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8) -> Result<Self, E> {
+		match Self::SIGNED {
+			true => atoi_build!(signed, iter, end),
+			false => atoi_build!(unsigned, iter, end),
+		}
+	}
+	
+	///Parsing used by `Atoi::atoi_iter_wait_stop_err`, types with a faster algorithm can override it.
+	fn atoi_num_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8) -> Result<Self, E> {
+		match Self::SIGNED {
+			true => atoi_build!(signed_wait_end, iter, end),
+			false => atoi_build!(unsigned_wait_end, iter, end),
+		}
+	}
+}
+
+impl<T: AtoiNum> AtoiPartial<T> for T {
+	fn atoi_iter_partial<'a, I: Iterator<Item=&'a u8>>(mut iter: I) -> AtoiPartialResult<T> {
+		match T::SIGNED {
+			true => atoi_build!(signed_partial, iter),
+			false => atoi_build!(unsigned_partial, iter),
+		}
+	}
+}
+
 macro_rules! atoi_build_type {
-	(u, $($t:ty),+ ) => {
-		$(
-		impl Atoi<$t> for $t {
-			fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(mut iter: I) -> AtoiResult< $t > {
-				atoi_build!(unsigned, iter);
+	//ATOI NUM, $fast(iter, end: Option<u8>, wait: bool) IS A FASTER PARSER
+	(num, $t:ty, $signed:expr $(, $fast:path)*) => {
+		impl AtoiNum for $t {
+			const ZERO: Self = 0;
+			const SIGNED: bool = $signed;
+			
+			#[inline(always)]
+			fn checked_mul_radix(&self, radix: u8) -> Option<Self> {
+				self.checked_mul(radix as Self)
 			}
-
-			fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8) -> AtoiResult< $t > {
-				atoi_build!(unsigned, iter, end);
+			#[inline(always)]
+			fn checked_add_digit(&self, digit: u8) -> Option<Self> {
+				self.checked_add(digit as Self)
 			}
-			fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8) -> AtoiResult< $t > {
-				atoi_build!(unsigned_wait_end, iter, end);
+			#[inline(always)]
+			fn checked_sub_digit(&self, digit: u8) -> Option<Self> {
+				self.checked_sub(digit as Self)
 			}
-		}
-		)+
-	};
-	(i, $($t:ty),+ ) => {
-		$(
-		impl Atoi<$t> for $t {
-			fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(mut iter: I) -> AtoiResult< $t > {
-				atoi_build!(signed, iter);
+			#[inline(always)]
+			fn saturating_bound(negative: bool) -> Option<Self> {
+				match negative {
+					true => Some(Self::MIN),
+					false => Some(Self::MAX),
+				}
 			}
-
-			fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8) -> AtoiResult< $t > {
-				atoi_build!(signed, iter, end);
+			#[inline(always)]
+			fn wrapping_push_digit(&self, radix: u8, digit: u8, negative: bool) -> Option<Self> {
+				let result = self.wrapping_mul(radix as Self);
+				match negative {
+					true => Some(result.wrapping_sub(digit as Self)),
+					false => Some(result.wrapping_add(digit as Self)),
+				}
+			}
+			#[inline]
+			fn checked_gcd(&self, denominator: &Self) -> Option<Self> {
+				//The euclidean remainder is not negative, a positive denominator gives a positive divisor.
+				let (mut a, mut b) = (*self, *denominator);
+				while b != 0 {
+					let r = a.checked_rem_euclid(b)?;
+					a = b;
+					b = r;
+				}
+				Some(a)
+			}
+			#[inline(always)]
+			fn checked_div_exact(&self, divisor: &Self) -> Option<Self> {
+				self.checked_div(*divisor)
+			}
+			#[inline(always)]
+			fn checked_mul_add(&self, mul: &Self, add: &Self) -> Option<Self> {
+				self.checked_mul(*mul)?.checked_add(*add)
+			}
+			$(
+			
+			#[inline(always)]
+			fn atoi_num_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<Self, E> {
+				$fast(iter, None, false)
+			}
+			#[inline(always)]
+			fn atoi_num_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Self, E> {
+				$fast(iter, Some(end), false)
 			}
-			fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(mut iter: I, end: u8) -> AtoiResult< $t > {
-				atoi_build!(signed_wait_end, iter, end);
+			#[inline(always)]
+			fn atoi_num_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Self, E> {
+				$fast(iter, Some(end), true)
 			}
+			)*
 		}
+		
+		atoi_build_num!($t);
+	};
+	(u, $($t:ty $(: $fast:path)*),+ ) => {
+		$(
+		atoi_build_type!(num, $t, false $(, $fast)*);
+		)+
+	};
+	(i, $($t:ty $(: $fast:path)*),+ ) => {
+		$(
+		atoi_build_type!(num, $t, true $(, $fast)*);
 		)+
 	}
 }
@@ -719,9 +2619,9 @@ atoi_build_type!(i, i8, i16, i32, i64, isize);
 
 
 //#[cfg(unstable)]
-atoi_build_type!(i, i128);
+atoi_build_type!(i, i128: int128::atoi_i128);
 //#[cfg(unstable)]
-atoi_build_type!(u, u128);
+atoi_build_type!(u, u128: int128::atoi_u128);
 
 
 #[cfg(test)]
@@ -885,6 +2785,403 @@ mod tests {
 			assert_eq!(iter.next(), Some(&b'E'));
 		}
 	}
+	
+	#[test]
+	fn atoi_prefix() {
+		assert_eq!(u32::atoi_prefix(b"123abc"), Result::Ok((123, &b"abc"[..])));
+		assert_eq!(u32::atoi_prefix(b"123"), Result::Ok((123, &b""[..])));
+		assert_eq!(u8::atoi_prefix(b"0255!"), Result::Ok((255, &b"!"[..])));
+		assert_eq!(i8::atoi_prefix(b"+12-3"), Result::Ok((12, &b"-3"[..])));
+		assert_eq!(i8::atoi_prefix(b"-128 "), Result::Ok((-128, &b" "[..])));
+		assert_eq!(u32::atoi_prefix(b"+1"), Result::Ok((1, &b""[..])));
+		assert_eq!(u8::atoi_prefix(b"+255+"), Result::Ok((255, &b"+"[..])));
+		
+		assert_eq!(u8::atoi_prefix(b"256abc"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i8::atoi_prefix(b"-129"), Result::Err(AtoiErr::Overflow));
+		
+		assert_eq!(u32::atoi_prefix(b""), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(u32::atoi_prefix(b"abc"), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(u32::atoi_prefix(b"-1"), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(u32::atoi_prefix(b"+"), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(i32::atoi_prefix(b"-"), Result::Err(AtoiErr::NoDigits));
+		assert_eq!(i32::atoi_prefix(b"-a1"), Result::Err(AtoiErr::NoDigits));
+	}
+	
+	#[test]
+	fn atoi_stop_reason() {
+		let array = b"1!2!3";
+		let stop = b'!';
+		
+		{//STOP
+			let mut iter = array.iter();
+			
+			assert_eq!(u8::atoi_iter_stop_reason(&mut iter, stop), Result::Ok((1, AtoiStop::Byte(1))));
+			assert_eq!(u8::atoi_iter_stop_reason(&mut iter, stop), Result::Ok((2, AtoiStop::Byte(1))));
+			assert_eq!(u8::atoi_iter_stop_reason(&mut iter, stop), Result::Ok((3, AtoiStop::End)));
+		}
+		
+		{//WAIT STOP
+			let array = b"-100!-5!";
+			let mut iter = array.iter();
+			
+			assert_eq!(u64::atoi_iter_wait_stop_reason(&mut iter, stop), (Result::Err(AtoiErr::ByteUnk(b'-')), AtoiStop::Byte(4)));
+			assert_eq!(i64::atoi_iter_wait_stop_reason(&mut iter, stop), (Result::Ok(-5), AtoiStop::Byte(2)));
+			assert_eq!(i64::atoi_iter_wait_stop_reason(&mut iter, stop), (Result::Ok(0), AtoiStop::End));
+		}
+		
+		assert_eq!(i8::atoi_stop_reason(b"!", stop), Result::Ok((0, AtoiStop::Byte(0))));
+		assert_eq!(i8::atoi_stop_reason(b"-!", stop), Result::Ok((0, AtoiStop::Byte(1))));
+		assert_eq!(i8::atoi_stop_reason(b"", stop), Result::Ok((0, AtoiStop::End)));
+		assert_eq!(u8::atoi_wait_stop_reason(b"999", stop), (Result::Err(AtoiErr::Overflow), AtoiStop::End));
+		assert_eq!(u8::atoi_wait_stop_reason(b"", stop), (Result::Ok(0), AtoiStop::End));
+		
+		//nothing consumed
+		assert_eq!(AtoiTrack::new(b"".iter()).stop(), AtoiStop::End);
+		assert_eq!(AtoiTrack::new(b"1!".iter()).stop(), AtoiStop::End);
+	}
+	
+	#[test]
+	fn atoi_stop_strict() {
+		assert_eq!(u32::atoi_stop_strict(b"5:hello", b':'), Result::Ok(5));
+		assert_eq!(u32::atoi_stop_strict(b":", b':'), Result::Ok(0));
+		assert_eq!(u32::atoi_stop_strict(b"5", b':'), Result::Err(AtoiErr::MissingStop));
+		assert_eq!(i32::atoi_stop_strict(b"-5", b':'), Result::Err(AtoiErr::MissingStop));
+		assert_eq!(u32::atoi_stop_strict(b"-5:", b':'), Result::Err(AtoiErr::ByteUnk(b'-')));
+		
+		assert_eq!(u8::atoi_wait_stop_strict(b"256", b':'), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_wait_stop_strict(b"25", b':'), Result::Err(AtoiErr::MissingStop));
+		
+		let array = b"x1:2:3";
+		let mut iter = array.iter();
+		
+		assert_eq!(u8::atoi_iter_wait_stop_strict(&mut iter, b':'), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(u8::atoi_iter_wait_stop_strict(&mut iter, b':'), Result::Ok(2));
+		assert_eq!(u8::atoi_iter_wait_stop_strict(&mut iter, b':'), Result::Err(AtoiErr::MissingStop));
+	}
+	
+	#[test]
+	fn atoi_iter_try() {
+		let array = b"-1!abc!2";
+		let stop = b'!';
+		let mut iter = array.iter();
+		
+		assert_eq!(u8::atoi_iter_try(&mut iter), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(u8::atoi_iter_stop_try(&mut iter, stop), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(u8::atoi_iter_wait_stop_try(&mut iter, stop), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(iter.as_slice(), &array[..]);
+		
+		assert_eq!(i8::atoi_iter_stop_try(&mut iter, stop), Result::Ok(-1));
+		assert_eq!(iter.as_slice(), b"abc!2");
+		
+		assert_eq!(i8::atoi_iter_wait_stop_try(&mut iter, stop), Result::Err(AtoiErr::ByteUnk(b'a')));
+		assert_eq!(iter.as_slice(), b"abc!2");
+		
+		assert_eq!(iter.position(|a| *a == stop), Some(3));
+		assert_eq!(i8::atoi_iter_try(&mut iter), Result::Ok(2));
+		assert_eq!(iter.next(), None);
+	}
+	
+	#[test]
+	fn atoi_list() {
+		let list = i16::atoi_list(b"10!-5!!1a!99999!7!", b'!', 8);
+		assert_eq!(list.values, vec![10, -5, 7]);
+		assert_eq!(list.errors, vec![
+			AtoiListErr { field: 2, offset: 6, err: AtoiErr::NoDigits },
+			AtoiListErr { field: 3, offset: 8, err: AtoiErr::ByteUnk(b'a') },
+			AtoiListErr { field: 4, offset: 14, err: AtoiErr::Overflow },
+		]);
+		assert_eq!(list.skipped_errors, 0);
+		
+		let list = u8::atoi_list(b"a!b!c!1", b'!', 1);
+		assert_eq!(list.values, vec![1]);
+		assert_eq!(list.errors, vec![
+			AtoiListErr { field: 0, offset: 0, err: AtoiErr::ByteUnk(b'a') },
+		]);
+		assert_eq!(list.skipped_errors, 2);
+		
+		let list = u8::atoi_list(b"", b'!', 1);
+		assert!(list.values.is_empty());
+		assert!(list.errors.is_empty());
+		
+		let list = u8::atoi_list(b"1,,2", b',', 4);
+		assert_eq!(list.values, vec![1, 2]);
+		assert_eq!(list.errors, vec![
+			AtoiListErr { field: 1, offset: 2, err: AtoiErr::NoDigits },
+		]);
+		
+		let list = u8::atoi_list(b",12x,", b',', 4);
+		assert_eq!(list.values, vec![]);
+		assert_eq!(list.errors, vec![
+			AtoiListErr { field: 0, offset: 0, err: AtoiErr::NoDigits },
+			AtoiListErr { field: 1, offset: 3, err: AtoiErr::ByteUnk(b'x') },
+		]);
+	}
+	
+	#[test]
+	fn atoi_err() {
+		type PosErr = (AtoiErr, usize);
+		
+		assert_eq!(u8::atoi_err::<PosErr>(b"255"), Result::Ok(255));
+		assert_eq!(u8::atoi_err::<PosErr>(b"256"), Result::Err((AtoiErr::Overflow, 2)));
+		assert_eq!(u8::atoi_err::<PosErr>(b"-1"), Result::Err((AtoiErr::ByteUnk(b'-'), 0)));
+		assert_eq!(i8::atoi_err::<PosErr>(b"-12a"), Result::Err((AtoiErr::ByteUnk(b'a'), 3)));
+		assert_eq!(i8::atoi_err::<PosErr>(b"a"), Result::Err((AtoiErr::ByteUnk(b'a'), 0)));
+		assert_eq!(i8::atoi_err::<PosErr>(b"+1000"), Result::Err((AtoiErr::Overflow, 4)));
+		
+		assert_eq!(u32::atoi_stop_err::<PosErr>(b"12!x", b'!'), Result::Ok(12));
+		assert_eq!(i32::atoi_stop_err::<PosErr>(b"-1x!", b'!'), Result::Err((AtoiErr::ByteUnk(b'x'), 2)));
+		
+		let array = b"-100!E";
+		let mut iter = array.iter();
+		assert_eq!(u64::atoi_iter_wait_stop_err::<PosErr, _>(&mut iter, b'!'), Result::Err((AtoiErr::ByteUnk(b'-'), 0)));
+		assert_eq!(iter.next(), Some(&b'E'));
+	}
+	
+	//Implementor of the required methods only, the `_err` variants are the defaults.
+	#[derive(Debug, PartialEq)]
+	struct Port(u16);
+	
+	impl Port {
+		fn check(num: u16) -> AtoiResult<Port> {
+			match num {
+				0 => Err(AtoiErr::Zero),
+				_ => Ok(Port(num)),
+			}
+		}
+	}
+	
+	impl Atoi<Port> for Port {
+		fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<Port> {
+			Port::check(u16::atoi_iter(iter)?)
+		}
+		
+		fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Port> {
+			Port::check(u16::atoi_iter_stop(iter, end)?)
+		}
+		
+		fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Port> {
+			Port::check(u16::atoi_iter_wait_stop(iter, end)?)
+		}
+	}
+	
+	#[test]
+	fn atoi_err_default() {
+		type PosErr = (AtoiErr, usize);
+		
+		assert_eq!(Port::atoi_err::<PosErr>(b"80"), Result::Ok(Port(80)));
+		assert_eq!(Port::atoi_err::<PosErr>(b"80x"), Result::Err((AtoiErr::ByteUnk(b'x'), 2)));
+		assert_eq!(Port::atoi_err::<PosErr>(b"65536"), Result::Err((AtoiErr::Overflow, 4)));
+		assert_eq!(Port::atoi_err::<PosErr>(b"0"), Result::Err((AtoiErr::Zero, 0)));
+		assert_eq!(Port::atoi_stop_err::<PosErr>(b"-1!", b'!'), Result::Err((AtoiErr::ByteUnk(b'-'), 0)));
+		
+		let array = b"1!2x3!0!4";
+		let mut iter = array.iter();
+		assert_eq!(Port::atoi_iter_wait_stop_err::<PosErr, _>(&mut iter, b'!'), Result::Ok(Port(1)));
+		assert_eq!(Port::atoi_iter_wait_stop_err::<PosErr, _>(&mut iter, b'!'), Result::Err((AtoiErr::ByteUnk(b'x'), 1)));
+		assert_eq!(Port::atoi_iter_wait_stop_err::<PosErr, _>(&mut iter, b'!'), Result::Err((AtoiErr::Zero, 0)));
+		assert_eq!(Port::atoi_iter_wait_stop_err::<PosErr, _>(&mut iter, b'!'), Result::Ok(Port(4)));
+	}
+	
+	#[test]
+	fn atoi_info() {
+		let info = |sign, leading_zeros, digits, consumed| AtoiInfo { sign, leading_zeros, digits, radix: 10, consumed };
+		
+		assert_eq!(u8::atoi_info(b"7"), Result::Ok((7, info(None, 0, 1, 1))));
+		assert_eq!(u8::atoi_info(b"007"), Result::Ok((7, info(None, 2, 3, 3))));
+		assert_eq!(u8::atoi_info(b"000"), Result::Ok((0, info(None, 2, 3, 3))));
+		assert_eq!(u8::atoi_info(b"100"), Result::Ok((100, info(None, 0, 3, 3))));
+		assert_eq!(i8::atoi_info(b"+5"), Result::Ok((5, info(Some(b'+'), 0, 1, 2))));
+		assert_eq!(i8::atoi_info(b"-0010"), Result::Ok((-10, info(Some(b'-'), 2, 4, 5))));
+		assert_eq!(i8::atoi_info(b""), Result::Ok((0, info(None, 0, 0, 0))));
+		
+		assert_eq!(u8::atoi_info(b"+5"), Result::Err(AtoiErr::ByteUnk(b'+')));
+		assert_eq!(u8::atoi_info(b"0256"), Result::Err(AtoiErr::Overflow));
+		
+		assert_eq!(i8::atoi_stop_info(b"-01!2", b'!'), Result::Ok((-1, info(Some(b'-'), 1, 2, 3))));
+		assert_eq!(i8::atoi_stop_info(b"!", b'!'), Result::Ok((0, info(None, 0, 0, 0))));
+	}
+	
+	#[test]
+	fn atoi_policy() {
+		//max self + 1
+		assert_eq!(u8::atoi_policy(b"256", AtoiOverflow::Error), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_policy(b"256", AtoiOverflow::Saturate), Result::Ok((255, true)));
+		assert_eq!(u8::atoi_policy(b"256", AtoiOverflow::Wrap), Result::Ok((0, true)));
+		assert_eq!(u8::atoi_policy(b"255", AtoiOverflow::Wrap), Result::Ok((255, false)));
+		assert_eq!(u32::atoi_policy(b"4294967296", AtoiOverflow::Wrap), Result::Ok((0, true)));
+		assert_eq!(u64::atoi_policy(b"18446744073709551616", AtoiOverflow::Saturate), Result::Ok((18446744073709551615, true)));
+		
+		//min self - 1
+		assert_eq!(i8::atoi_policy(b"-129", AtoiOverflow::Error), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i8::atoi_policy(b"-129", AtoiOverflow::Saturate), Result::Ok((-128, true)));
+		assert_eq!(i8::atoi_policy(b"-129", AtoiOverflow::Wrap), Result::Ok((127, true)));
+		assert_eq!(i8::atoi_policy(b"128", AtoiOverflow::Wrap), Result::Ok((-128, true)));
+		assert_eq!(i16::atoi_policy(b"+99999", AtoiOverflow::Saturate), Result::Ok((32767, true)));
+		
+		//#[cfg(unstable)]
+		assert_eq!(i128::atoi_policy(b"-1701411834604692317316873037158841057280", AtoiOverflow::Saturate), Result::Ok((i128::MIN, true)));
+		
+		//the rest of the number is checked after overflow
+		assert_eq!(u8::atoi_policy(b"999x", AtoiOverflow::Saturate), Result::Err(AtoiErr::ByteUnk(b'x')));
+		
+		{//STOP
+			let array = b"999!-1!1";
+			let mut iter = array.iter();
+			
+			assert_eq!(u8::atoi_iter_stop_policy(&mut iter, b'!', AtoiOverflow::Saturate), Result::Ok((255, true)));
+			assert_eq!(u8::atoi_iter_stop_policy(&mut iter, b'!', AtoiOverflow::Saturate), Result::Err(AtoiErr::ByteUnk(b'-')));
+			assert_eq!(iter.next(), Some(&b'1'));
+		}
+		
+		{//WAIT STOP
+			let array = b"999!-1!1";
+			let mut iter = array.iter();
+			
+			assert_eq!(u8::atoi_iter_wait_stop_policy(&mut iter, b'!', AtoiOverflow::Error), Result::Err(AtoiErr::Overflow));
+			assert_eq!(u8::atoi_iter_wait_stop_policy(&mut iter, b'!', AtoiOverflow::Wrap), Result::Err(AtoiErr::ByteUnk(b'-')));
+			assert_eq!(u8::atoi_iter_wait_stop_policy(&mut iter, b'!', AtoiOverflow::Wrap), Result::Ok((1, false)));
+		}
+		
+		assert_eq!(i8::atoi_stop_policy(b"-1!", b'!', AtoiOverflow::Error), i8::atoi_stop(b"-1!", b'!').map(|a| (a, false)));
+		
+		//wide integers
+		assert_eq!(U256::atoi_policy(b"-1", AtoiOverflow::Saturate), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(U256::atoi_policy(b"1157920892373161954235709850086879078532699846656405640394575840079131296399350", AtoiOverflow::Saturate), Result::Ok((U256::MAX, true)));
+		assert_eq!(U256::atoi_policy(b"115792089237316195423570985008687907853269984665640564039457584007913129639936", AtoiOverflow::Wrap), Result::Ok((U256::ZERO, true)));
+		assert_eq!(I256::atoi_policy(b"-57896044618658097711785492504343953926634992332820282019728792003956564819969", AtoiOverflow::Wrap), Result::Ok((I256::MAX, true)));
+		assert_eq!(I512::atoi_policy(&[b'9'; 200], AtoiOverflow::Saturate), Result::Ok((I512::MAX, true)));
+		
+		//the error type of the caller
+		assert_eq!(u8::atoi_policy_err::<(AtoiErr, usize)>(b"256", AtoiOverflow::Error), Result::Err((AtoiErr::Overflow, 2)));
+		assert_eq!(i8::atoi_policy_err::<(AtoiErr, usize)>(b"-999x", AtoiOverflow::Saturate), Result::Err((AtoiErr::ByteUnk(b'x'), 4)));
+		assert_eq!(u16::atoi_stop_policy_err::<(AtoiErr, usize)>(b"70000!", b'!', AtoiOverflow::Wrap), Result::Ok((4464, true)));
+		{
+			let array = b"1x!2";
+			let mut iter = array.iter();
+			
+			assert_eq!(U256::atoi_iter_wait_stop_policy_err::<(AtoiErr, usize), _>(&mut iter, b'!', AtoiOverflow::Wrap), Result::Err((AtoiErr::ByteUnk(b'x'), 1)));
+			assert_eq!(iter.next(), Some(&b'2'));
+		}
+	}
+	
+	#[test]
+	fn atoi_partial() {
+		assert_eq!(u8::atoi_partial(b"255"), Result::Ok(255));
+		assert_eq!(i8::atoi_partial(b"-128"), Result::Ok(-128));
+		
+		assert_eq!(u8::atoi_partial(b"25x"), Result::Err(AtoiPartialErr { err: AtoiErr::ByteUnk(b'x'), value: 25, digits: 2 }));
+		assert_eq!(u8::atoi_partial(b"2560"), Result::Err(AtoiPartialErr { err: AtoiErr::Overflow, value: 25, digits: 2 }));
+		assert_eq!(u64::atoi_partial(b"-1"), Result::Err(AtoiPartialErr { err: AtoiErr::ByteUnk(b'-'), value: 0, digits: 0 }));
+		
+		assert_eq!(i8::atoi_partial(b"-129"), Result::Err(AtoiPartialErr { err: AtoiErr::Overflow, value: -12, digits: 2 }));
+		assert_eq!(i16::atoi_partial(b"+12.5"), Result::Err(AtoiPartialErr { err: AtoiErr::ByteUnk(b'.'), value: 12, digits: 2 }));
+		
+		//#[cfg(unstable)]
+		assert_eq!(i128::atoi_partial(b"-42 "), Result::Err(AtoiPartialErr { err: AtoiErr::ByteUnk(b' '), value: -42, digits: 2 }));
+	}
+	
+	#[test]
+	fn atoi_range() {
+		assert_eq!(u16::atoi_range(b"65535", 1..), Result::Ok(65535));
+		assert_eq!(u16::atoi_range(b"0", 1..), Result::Err(AtoiErr::OutOfRange(AnyInt::U16(0))));
+		assert_eq!(u8::atoi_range(b"100", ..=100), Result::Ok(100));
+		assert_eq!(i8::atoi_range(b"-1", 0..10), Result::Err(AtoiErr::OutOfRange(AnyInt::I8(-1))));
+		assert_eq!(i8::atoi_range(b"-129", 0..10), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_stop_range(b"12:", b':', 1..=12), Result::Ok(12));
+		assert_eq!(<&u8>::atoi_range(b"13", 1..=12), Result::Err(AtoiErr::OutOfRange(AnyInt::U8(13))));
+		assert_eq!(usize::atoi_range(b"4096", ..=4096), Result::Ok(4096));
+		assert_eq!(usize::atoi_range(b"4097", ..=4096), Result::Err(AtoiErr::OutOfRange(AnyInt::U64(4097))));
+		assert_eq!(isize::atoi_range(b"-5", -4..), Result::Err(AtoiErr::OutOfRange(AnyInt::I64(-5))));
+		
+		let array = b"101!x!99";
+		let mut iter = array.iter();
+		assert_eq!(u8::atoi_iter_wait_stop_range(&mut iter, b'!', 0..=100), Result::Err(AtoiErr::OutOfRange(AnyInt::U8(101))));
+		assert_eq!(u8::atoi_iter_wait_stop_range(&mut iter, b'!', 0..=100), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(u8::atoi_iter_wait_stop_range(&mut iter, b'!', 0..=100), Result::Ok(99));
+	}
+	
+	#[test]
+	fn atoi_null() {
+		assert_eq!(u8::atoi_null(b"NULL", ATOI_NULLS), Result::Ok(None));
+		assert_eq!(u8::atoi_null(b"NULL1", ATOI_NULLS), Result::Err(AtoiErr::ByteUnk(b'N')));
+		assert_eq!(i8::atoi_null(b"-", ATOI_NULLS), Result::Ok(None));
+		assert_eq!(i8::atoi_null(b"-1", ATOI_NULLS), Result::Ok(Some(-1)));
+		assert_eq!(i8::atoi_null(b"-", &[]), Result::Ok(Some(0)));
+		assert_eq!(u8::atoi_stop_null(b"\\N,", b',', ATOI_NULLS), Result::Ok(None));
+		assert_eq!(<&u8>::atoi_stop_null(b"5,", b',', ATOI_NULLS), Result::Ok(Some(5)));
+		
+		let array = b",NULL,NUL,3";
+		let mut iter = array.iter();
+		assert_eq!(u8::atoi_iter_stop_null(&mut iter, b',', ATOI_NULLS), Result::Ok(None));
+		assert_eq!(u8::atoi_iter_stop_null(&mut iter, b',', ATOI_NULLS), Result::Ok(None));
+		assert_eq!(u8::atoi_iter_wait_stop_null(&mut iter, b',', ATOI_NULLS), Result::Err(AtoiErr::ByteUnk(b'N')));
+		assert_eq!(u8::atoi_iter_stop_null(&mut iter, b',', ATOI_NULLS), Result::Ok(Some(3)));
+		assert_eq!(u8::atoi_iter_stop_null(&mut iter, b',', ATOI_NULLS), Result::Ok(None));
+		
+		let array = b"NULL";
+		let mut iter = array.iter();
+		assert_eq!(u8::atoi_iter_null(&mut iter, ATOI_NULLS), Result::Ok(None));
+		assert_eq!(iter.next(), None);
+	}
+	
+	#[test]
+	fn atoi_ref() {
+		use std::num::{NonZeroU32, Wrapping};
+		
+		assert_eq!(<&NonZeroU32>::atoi(b"42"), NonZeroU32::atoi(b"42"));
+		assert_eq!(<&NonZeroU32>::atoi(b"0"), Result::Err(AtoiErr::Zero));
+		assert_eq!(<&mut NonZeroU32>::atoi_stop(b"7!", b'!'), Result::Ok(NonZeroU32::new(7).unwrap()));
+		assert_eq!(<&Wrapping<u8>>::atoi(b"257"), Result::Ok(Wrapping(1)));
+		assert_eq!(<&mut Wrapping<u8>>::atoi_err::<(AtoiErr, usize)>(b"1x"), Result::Err((AtoiErr::ByteUnk(b'x'), 1)));
+		assert_eq!(<&&u8>::atoi(b"255"), Result::Ok(255));
+		assert_eq!(<&Port>::atoi(b"80"), Result::Ok(Port(80)));
+	}
+	
+	#[test]
+	fn atoi_num() {
+		//Money in hundredths, limited to +-10000.00
+		#[derive(Debug, PartialEq)]
+		struct Money(i32);
+		
+		impl AtoiNum for Money {
+			const ZERO: Self = Money(0);
+			const SIGNED: bool = true;
+			
+			fn checked_mul_radix(&self, radix: u8) -> Option<Self> {
+				self.0.checked_mul(radix as i32).filter(|a| a.abs() <= 1000000).map(Money)
+			}
+			fn checked_add_digit(&self, digit: u8) -> Option<Self> {
+				self.0.checked_add(digit as i32).filter(|a| *a <= 1000000).map(Money)
+			}
+			fn checked_sub_digit(&self, digit: u8) -> Option<Self> {
+				self.0.checked_sub(digit as i32).filter(|a| *a >= -1000000).map(Money)
+			}
+			fn saturating_bound(negative: bool) -> Option<Self> {
+				match negative {
+					true => Some(Money(-1000000)),
+					false => Some(Money(1000000)),
+				}
+			}
+		}
+		
+		atoi_build_num!(Money);
+		
+		assert_eq!(Money::atoi(b"-1000000"), Result::Ok(Money(-1000000)));
+		assert_eq!(Money::atoi(b"1000001"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(Money::atoi_err::<(AtoiErr, usize)>(b"+12x"), Result::Err((AtoiErr::ByteUnk(b'x'), 3)));
+		assert_eq!(<&Money>::atoi_stop(b"150 ", b' '), Result::Ok(Money(150)));
+		assert_eq!(Money::atoi_partial(b"-7.5"), Result::Err(AtoiPartialErr { err: AtoiErr::ByteUnk(b'.'), value: Money(-7), digits: 1 }));
+		
+		let array = b"-5!x1!2";
+		let mut iter = array.iter();
+		assert_eq!(Money::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(Money(-5)));
+		assert_eq!(Money::atoi_iter_wait_stop(&mut iter, b'!'), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(Money::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(Money(2)));
+		
+		//no wrapping hook, the overflow is an error
+		assert_eq!(Money::atoi_policy(b"-2000000", AtoiOverflow::Saturate), Result::Ok((Money(-1000000), true)));
+		assert_eq!(Money::atoi_policy(b"2000000", AtoiOverflow::Wrap), Result::Err(AtoiErr::Overflow));
+	}
 }
 
 
@@ -0,0 +1,136 @@
+use std::iter;
+use Atoi;
+use AtoiResult;
+
+///Parsing of unsigned numbers with a leading minus, `-a` is the two's complement of `a` (`-1` is `T::MAX`).
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::AtoiNeg;
+///use cluatoi::AtoiErr;
+///
+///fn main() {
+///	assert_eq!(u32::atoi_neg(b"-1"), Ok(u32::MAX));
+///	assert_eq!(u8::atoi_neg(b"-255"), Ok(1));
+///	assert_eq!(u8::atoi_neg(b"-256"), Err(AtoiErr::Overflow));
+///	assert_eq!(u8::atoi_neg(b"255"), Ok(255));
+///}
+///```
+pub trait AtoiNeg<T> {
+	///Array parsing, the minus is the two's complement.
+	#[inline]
+	fn atoi_neg(array: &[u8]) -> AtoiResult<T> {
+		Self::atoi_iter_neg(array.iter())
+	}
+	
+	///Array parsing and stopping on the 'X' character, the minus is the two's complement.
+	#[inline]
+	fn atoi_stop_neg(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atoi_iter_stop_neg(array.iter(), stop)
+	}
+	
+	///An array analysis waiting for an "X" character even if an error occurred, the minus is the two's complement.
+	#[inline]
+	fn atoi_wait_stop_neg(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atoi_iter_wait_stop_neg(array.iter(), stop)
+	}
+	
+	///Array parsing using an iterator, the minus is the two's complement.
+	fn atoi_iter_neg<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T>;
+	
+	///Array parsing using an iterator and stopping on the 'X' character, the minus is the two's complement.
+	///
+	///```rust
+	///use cluatoi::AtoiNeg;
+	///
+	///fn main() {
+	///	let array = b"-1,-2,3";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u16::atoi_iter_stop_neg(&mut array_iter, b','), Ok(65535));
+	///	assert_eq!(u16::atoi_iter_stop_neg(&mut array_iter, b','), Ok(65534));
+	///	assert_eq!(u16::atoi_iter_stop_neg(&mut array_iter, b','), Ok(3));
+	///}
+	///```
+	fn atoi_iter_stop_neg<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T>;
+	
+	///An array analysis using an iterator and waiting for an "X" character even if an error occurred, the minus is the two's complement.
+	fn atoi_iter_wait_stop_neg<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T>;
+}
+
+//The magnitude is parsed by Atoi of the type, so the stop and the errors are the same.
+fn atoi_neg<'a, T, I>(mut iter: I, end: Option<u8>, wait: bool, neg: fn(T) -> T) -> AtoiResult<T>
+	where T: Atoi<T>, I: Iterator<Item=&'a u8> {
+	
+	fn parse<'a, T: Atoi<T>, I: Iterator<Item=&'a u8>>(iter: I, end: Option<u8>, wait: bool) -> AtoiResult<T> {
+		match (end, wait) {
+			(Some(end), true) => T::atoi_iter_wait_stop(iter, end),
+			(Some(end), false) => T::atoi_iter_stop(iter, end),
+			(None, _) => T::atoi_iter(iter),
+		}
+	}
+	
+	match iter.next() {
+		Some(&b'-') => parse(iter, end, wait).map(neg),
+		Some(a) => parse(iter::once(a).chain(iter), end, wait),
+		None => parse(iter, end, wait),
+	}
+}
+
+macro_rules! atoi_neg_build {
+	($($t:ty),+) => {
+		$(
+		impl AtoiNeg<$t> for $t {
+			#[inline]
+			fn atoi_iter_neg<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<$t> {
+				atoi_neg(iter, None, false, <$t>::wrapping_neg)
+			}
+			
+			#[inline]
+			fn atoi_iter_stop_neg<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<$t> {
+				atoi_neg(iter, Some(end), false, <$t>::wrapping_neg)
+			}
+			
+			#[inline]
+			fn atoi_iter_wait_stop_neg<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<$t> {
+				atoi_neg(iter, Some(end), true, <$t>::wrapping_neg)
+			}
+		}
+		)+
+	};
+}
+
+atoi_neg_build!(u8, u16, u32, u64, usize, u128);
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use AtoiErr;
+	
+	#[test]
+	fn atoi_neg() {
+		assert_eq!(u32::atoi_neg(b"-1"), Result::Ok(u32::MAX));
+		assert_eq!(u64::atoi_neg(b"-0"), Result::Ok(0));
+		assert_eq!(u64::atoi_neg(b"-"), Result::Ok(0));
+		assert_eq!(u64::atoi_neg(b""), Result::Ok(0));
+		assert_eq!(u8::atoi_neg(b"-128"), Result::Ok(128));
+		assert_eq!(u8::atoi_neg(b"-256"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_neg(b"--1"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(u8::atoi_neg(b"+1"), Result::Err(AtoiErr::ByteUnk(b'+')));
+		assert_eq!(u128::atoi_neg(b"-340282366920938463463374607431768211455"), Result::Ok(1));
+	}
+	
+	#[test]
+	fn atoi_stop_neg() {
+		assert_eq!(u16::atoi_stop_neg(b"-2 ", b' '), Result::Ok(65534));
+		assert_eq!(u16::atoi_stop_neg(b" -2", b' '), Result::Ok(0));
+		
+		let array = b"-1!-x1!-99999!7";
+		let mut iter = array.iter();
+		assert_eq!(u16::atoi_iter_wait_stop_neg(&mut iter, b'!'), Result::Ok(u16::MAX));
+		assert_eq!(u16::atoi_iter_wait_stop_neg(&mut iter, b'!'), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(u16::atoi_iter_wait_stop_neg(&mut iter, b'!'), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u16::atoi_iter_wait_stop_neg(&mut iter, b'!'), Result::Ok(7));
+	}
+}
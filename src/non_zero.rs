@@ -0,0 +1,122 @@
+use std::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use std::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize};
+use Atoi;
+use AtoiErr;
+use AtoiOverflow;
+use AtoiPolicy;
+use AtoiResult;
+use FromAtoiErr;
+
+//Atoi of NonZero types, parsed as the primitive type and checked for zero.
+macro_rules! atoi_build_non_zero {
+	($($t:ty => $p:ty),+ ) => {
+		$(
+		impl Atoi<$t> for $t {
+			#[inline]
+			fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult< $t > {
+				Self::atoi_iter_err(iter)
+			}
+			
+			#[inline]
+			fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult< $t > {
+				Self::atoi_iter_stop_err(iter, end)
+			}
+			
+			#[inline]
+			fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult< $t > {
+				Self::atoi_iter_wait_stop_err(iter, end)
+			}
+			
+			fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result< $t, E > {
+				let num = <$p>::atoi_iter_err(iter)?;
+				<$t>::new(num).ok_or_else(|| E::from_atoi_err(AtoiErr::Zero, 0))
+			}
+
+			fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result< $t, E > {
+				let num = <$p>::atoi_iter_stop_err(iter, end)?;
+				<$t>::new(num).ok_or_else(|| E::from_atoi_err(AtoiErr::Zero, 0))
+			}
+			fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result< $t, E > {
+				let num = <$p>::atoi_iter_wait_stop_err(iter, end)?;
+				<$t>::new(num).ok_or_else(|| E::from_atoi_err(AtoiErr::Zero, 0))
+			}
+		}
+		
+		//A clamped or wrapped zero is `AtoiErr::Zero`.
+		impl AtoiPolicy<$t> for $t {
+			#[inline]
+			fn atoi_iter_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, policy: AtoiOverflow) -> AtoiResult<($t, bool)> {
+				Self::atoi_iter_policy_err(iter, policy)
+			}
+			
+			#[inline]
+			fn atoi_iter_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> AtoiResult<($t, bool)> {
+				Self::atoi_iter_stop_policy_err(iter, end, policy)
+			}
+			
+			#[inline]
+			fn atoi_iter_wait_stop_policy<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> AtoiResult<($t, bool)> {
+				Self::atoi_iter_wait_stop_policy_err(iter, end, policy)
+			}
+			
+			fn atoi_iter_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, policy: AtoiOverflow) -> Result<($t, bool), E> {
+				let (num, overflow) = <$p>::atoi_iter_policy_err(iter, policy)?;
+				<$t>::new(num).map(|num| (num, overflow)).ok_or_else(|| E::from_atoi_err(AtoiErr::Zero, 0))
+			}
+			
+			fn atoi_iter_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> Result<($t, bool), E> {
+				let (num, overflow) = <$p>::atoi_iter_stop_policy_err(iter, end, policy)?;
+				<$t>::new(num).map(|num| (num, overflow)).ok_or_else(|| E::from_atoi_err(AtoiErr::Zero, 0))
+			}
+			
+			fn atoi_iter_wait_stop_policy_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8, policy: AtoiOverflow) -> Result<($t, bool), E> {
+				let (num, overflow) = <$p>::atoi_iter_wait_stop_policy_err(iter, end, policy)?;
+				<$t>::new(num).map(|num| (num, overflow)).ok_or_else(|| E::from_atoi_err(AtoiErr::Zero, 0))
+			}
+		}
+		)+
+	};
+}
+
+atoi_build_non_zero!(NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize);
+atoi_build_non_zero!(NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroI128 => i128, NonZeroIsize => isize);
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	#[test]
+	fn non_zero() {
+		assert_eq!(NonZeroU32::atoi(b"42"), Result::Ok(NonZeroU32::new(42).unwrap()));
+		assert_eq!(NonZeroI8::atoi(b"-128"), Result::Ok(NonZeroI8::new(-128).unwrap()));
+		assert_eq!(NonZeroU64::atoi(b"0"), Result::Err(AtoiErr::Zero));
+		assert_eq!(NonZeroI16::atoi(b"-000"), Result::Err(AtoiErr::Zero));
+		assert_eq!(NonZeroU8::atoi(b"256"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(NonZeroU8::atoi(b"-1"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(NonZeroU128::atoi_err::<(AtoiErr, usize)>(b"1x"), Result::Err((AtoiErr::ByteUnk(b'x'), 1)));
+		
+		assert_eq!(NonZeroUsize::atoi_stop(b"7!", b'!'), Result::Ok(NonZeroUsize::new(7).unwrap()));
+		assert_eq!(NonZeroIsize::atoi_stop(b"!", b'!'), Result::Err(AtoiErr::Zero));
+	}
+	
+	#[test]
+	fn non_zero_wait_stop() {
+		let array = b"0!x1!5";
+		let mut iter = array.iter();
+		
+		assert_eq!(NonZeroU32::atoi_iter_wait_stop(&mut iter, b'!'), Result::Err(AtoiErr::Zero));
+		assert_eq!(NonZeroU32::atoi_iter_wait_stop(&mut iter, b'!'), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(NonZeroU32::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(NonZeroU32::new(5).unwrap()));
+		assert_eq!(iter.next(), None);
+	}
+	
+	#[test]
+	fn non_zero_policy() {
+		assert_eq!(NonZeroU8::atoi_policy(b"300", AtoiOverflow::Saturate), Result::Ok((NonZeroU8::new(255).unwrap(), true)));
+		assert_eq!(NonZeroI8::atoi_policy(b"-300", AtoiOverflow::Wrap), Result::Ok((NonZeroI8::new(-44).unwrap(), true)));
+		assert_eq!(NonZeroU8::atoi_policy(b"256", AtoiOverflow::Wrap), Result::Err(AtoiErr::Zero));
+		assert_eq!(NonZeroU8::atoi_policy(b"0", AtoiOverflow::Saturate), Result::Err(AtoiErr::Zero));
+		assert_eq!(NonZeroU16::atoi_policy_err::<(AtoiErr, usize)>(b"70000", AtoiOverflow::Error), Result::Err((AtoiErr::Overflow, 4)));
+	}
+}
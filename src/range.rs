@@ -0,0 +1,107 @@
+use AnyInt;
+use Atoi;
+use AtoiErr;
+use AtoiResult;
+use FromAtoiErr;
+
+///Number in the range `MIN..=MAX` known at compile time, otherwise `AtoiErr::OutOfRange`.
+///
+///The bounds are `i128`, so `Bounded<u128, ..>` cannot hold the numbers above `i128::MAX`, they are always `AtoiErr::OutOfRange`.
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::{Atoi, AtoiErr, AnyInt, Bounded};
+///
+///type Port = Bounded<u16, 1, 65535>;
+///type Month = Bounded<u8, 1, 12>;
+///
+///fn main() {
+///	assert_eq!(Port::atoi(b"8080"), Ok(Bounded(8080)));
+///	assert_eq!(Port::atoi(b"0"), Err(AtoiErr::OutOfRange(AnyInt::U16(0))));
+///
+///	let array = b"12.13";
+///	let mut array_iter = array.iter();
+///	assert_eq!(Month::atoi_iter_stop(&mut array_iter, b'.'), Ok(Bounded(12)));
+///	assert_eq!(Month::atoi_iter_stop(&mut array_iter, b'.'), Err(AtoiErr::OutOfRange(AnyInt::U8(13))));
+///}
+///```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128>(pub T);
+
+impl<T, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+	///The number itself.
+	#[inline]
+	pub fn get(self) -> T {
+		self.0
+	}
+}
+
+impl<T, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> where T: Into<AnyInt> + Copy {
+	//pos is 0, the error of the whole number.
+	#[inline]
+	fn check<E: FromAtoiErr>(num: T) -> Result<Self, E> {
+		let any = num.into();
+		match any.to_i128() {
+			Some(a) if MIN <= a && a <= MAX => Ok(Bounded(num)),
+			_ => Err(E::from_atoi_err(AtoiErr::OutOfRange(any), 0)),
+		}
+	}
+}
+
+impl<T, const MIN: i128, const MAX: i128> Atoi<Bounded<T, MIN, MAX>> for Bounded<T, MIN, MAX> where T: Atoi<T> + Into<AnyInt> + Copy {
+	#[inline]
+	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<Self> {
+		Self::atoi_iter_err(iter)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Self> {
+		Self::atoi_iter_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Self> {
+		Self::atoi_iter_wait_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<Self, E> {
+		Self::check(T::atoi_iter_err(iter)?)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Self, E> {
+		Self::check(T::atoi_iter_stop_err(iter, end)?)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Self, E> {
+		Self::check(T::atoi_iter_wait_stop_err(iter, end)?)
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	#[test]
+	fn bounded() {
+		assert_eq!(Bounded::<u8, 0, 100>::atoi(b"100"), Result::Ok(Bounded(100)));
+		assert_eq!(Bounded::<u8, 0, 100>::atoi(b"101"), Result::Err(AtoiErr::OutOfRange(AnyInt::U8(101))));
+		assert_eq!(Bounded::<u8, 0, 100>::atoi(b"256"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(Bounded::<i8, -10, 10>::atoi(b"-11"), Result::Err(AtoiErr::OutOfRange(AnyInt::I8(-11))));
+		assert_eq!(Bounded::<u128, 0, { i128::MAX }>::atoi(b"170141183460469231731687303715884105728"), Result::Err(AtoiErr::OutOfRange(AnyInt::U128(1 << 127))));
+		assert_eq!(Bounded::<u16, 1, 65535>::atoi_err::<(AtoiErr, usize)>(b"0"), Result::Err((AtoiErr::OutOfRange(AnyInt::U16(0)), 0)));
+		assert_eq!(Bounded::<usize, 1, 64>::atoi(b"64"), Result::Ok(Bounded(64)));
+		assert_eq!(Bounded::<usize, 1, 64>::atoi(b"65"), Result::Err(AtoiErr::OutOfRange(AnyInt::U64(65))));
+		assert_eq!(Bounded::<isize, -1, 1>::atoi(b"-2"), Result::Err(AtoiErr::OutOfRange(AnyInt::I64(-2))));
+		
+		let array = b"50!150!x!7";
+		let mut iter = array.iter();
+		assert_eq!(Bounded::<u32, 1, 100>::atoi_iter_wait_stop(&mut iter, b'!').map(Bounded::get), Result::Ok(50));
+		assert_eq!(Bounded::<u32, 1, 100>::atoi_iter_wait_stop(&mut iter, b'!'), Result::Err(AtoiErr::OutOfRange(AnyInt::U32(150))));
+		assert_eq!(Bounded::<u32, 1, 100>::atoi_iter_wait_stop(&mut iter, b'!'), Result::Err(AtoiErr::ByteUnk(b'x')));
+		assert_eq!(Bounded::<u32, 1, 100>::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(Bounded(7)));
+	}
+}
@@ -0,0 +1,301 @@
+use AtoiErr;
+use AtoiNum;
+use AtoiResult;
+use FromAtoiErr;
+use atoi_skip_stop;
+
+///Parsing of the fraction `[+-]numerator/denominator`, the mixed number `[+-]whole numerator/denominator`
+///or the integer into the reduced `(numerator, denominator)` pair.
+///
+///The denominator is positive, the sign is in the numerator. Every part and the result must fit into the type (`AtoiErr::Overflow`),
+///the reduction and the whole part use `AtoiNum::checked_gcd`, `AtoiNum::checked_div_exact` and `AtoiNum::checked_mul_add`.
+///The zero denominator is `AtoiErr::Zero`, the mixed number ending before the '/' of its fraction is `AtoiErr::MissingStop`,
+///the '/' needs the digits before it and the missing digits of the denominator are zero as in `Atoi`.
+///The space separates the digits of the whole part and of the numerator only.
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::{AtoiRatio, AtoiErr};
+///
+///fn main() {
+///	assert_eq!(u32::atoi_ratio(b"3/4"), Ok((3, 4)));
+///	assert_eq!(i32::atoi_ratio(b"-7/2"), Ok((-7, 2)));
+///	assert_eq!(u32::atoi_ratio(b"1 1/2"), Ok((3, 2)));
+///	assert_eq!(i64::atoi_ratio(b"6/8"), Ok((3, 4)));
+///	assert_eq!(u8::atoi_ratio(b"5"), Ok((5, 1)));
+///
+///	assert_eq!(u32::atoi_ratio(b"1/0"), Err(AtoiErr::Zero));
+///	assert_eq!(u32::atoi_ratio(b"1 2"), Err(AtoiErr::MissingStop));
+///	assert_eq!(u32::atoi_ratio(b"/2"), Err(AtoiErr::ByteUnk(b'/')));
+///	assert_eq!(u8::atoi_ratio(b"1 1/1"), Ok((2, 1)));
+///	assert_eq!(u8::atoi_ratio(b"255 1/1"), Err(AtoiErr::Overflow));
+///	assert_eq!(u8::atoi_ratio(b"1000/10"), Err(AtoiErr::Overflow));
+///}
+///```
+pub trait AtoiRatio<T> {
+	///`Atoi::atoi` of the fraction.
+	#[inline]
+	fn atoi_ratio(array: &[u8]) -> AtoiResult<(T, T)> {
+		Self::atoi_iter_ratio(array.iter())
+	}
+	
+	///`Atoi::atoi_stop` of the fraction.
+	#[inline]
+	fn atoi_stop_ratio(array: &[u8], stop: u8) -> AtoiResult<(T, T)> {
+		Self::atoi_iter_stop_ratio(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_wait_stop` of the fraction.
+	#[inline]
+	fn atoi_wait_stop_ratio(array: &[u8], stop: u8) -> AtoiResult<(T, T)> {
+		Self::atoi_iter_wait_stop_ratio(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_err` of the fraction.
+	///
+	///`AtoiErr::Zero`, `AtoiErr::MissingStop` and the overflow of the reduced fraction are at the end of the number.
+	///
+	///```rust
+	///use cluatoi::{AtoiRatio, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_ratio_err::<(AtoiErr, usize)>(b"1/x"), Err((AtoiErr::ByteUnk(b'x'), 2)));
+	///	assert_eq!(u32::atoi_ratio_err::<(AtoiErr, usize)>(b"1/00"), Err((AtoiErr::Zero, 4)));
+	///}
+	///```
+	#[inline]
+	fn atoi_ratio_err<E: FromAtoiErr>(array: &[u8]) -> Result<(T, T), E> {
+		Self::atoi_iter_ratio_err(array.iter())
+	}
+	
+	///`Atoi::atoi_stop_err` of the fraction.
+	#[inline]
+	fn atoi_stop_ratio_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<(T, T), E> {
+		Self::atoi_iter_stop_ratio_err(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_wait_stop_err` of the fraction.
+	#[inline]
+	fn atoi_wait_stop_ratio_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<(T, T), E> {
+		Self::atoi_iter_wait_stop_ratio_err(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_iter` of the fraction.
+	#[inline]
+	fn atoi_iter_ratio<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<(T, T)> {
+		Self::atoi_iter_ratio_err(iter)
+	}
+	
+	///`Atoi::atoi_iter_stop` of the fraction.
+	///
+	///```rust
+	///use cluatoi::AtoiRatio;
+	///
+	///fn main() {
+	///	let array = b"1/4;2 2/4;3";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u16::atoi_iter_stop_ratio(&mut array_iter, b';'), Ok((1, 4)));
+	///	assert_eq!(u16::atoi_iter_stop_ratio(&mut array_iter, b';'), Ok((5, 2)));
+	///	assert_eq!(u16::atoi_iter_stop_ratio(&mut array_iter, b';'), Ok((3, 1)));
+	///}
+	///```
+	#[inline]
+	fn atoi_iter_stop_ratio<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, T)> {
+		Self::atoi_iter_stop_ratio_err(iter, end)
+	}
+	
+	///`Atoi::atoi_iter_wait_stop` of the fraction.
+	#[inline]
+	fn atoi_iter_wait_stop_ratio<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<(T, T)> {
+		Self::atoi_iter_wait_stop_ratio_err(iter, end)
+	}
+	
+	///`Atoi::atoi_iter_err` of the fraction.
+	fn atoi_iter_ratio_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<(T, T), E>;
+	
+	///`Atoi::atoi_iter_stop_err` of the fraction.
+	fn atoi_iter_stop_ratio_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<(T, T), E>;
+	
+	///`Atoi::atoi_iter_wait_stop_err` of the fraction.
+	fn atoi_iter_wait_stop_ratio_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<(T, T), E>;
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+	Whole,
+	Numerator,
+	Denominator,
+}
+
+//The whole part and the numerator are accumulated with the sign of the number, so `whole * denominator + numerator` keeps it.
+fn atoi_ratio<'a, T, E, I>(mut iter: I, end: Option<u8>, wait: bool) -> Result<(T, T), E>
+	where T: AtoiNum, E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let mut negative = false;
+	let mut whole = T::ZERO;
+	let mut numerator = T::ZERO;
+	let mut current = T::ZERO;
+	//Digits and nonzero digits since the last separator.
+	let mut digits = false;
+	let mut nonzero = false;
+	let mut state = State::Whole;
+	let mut pos = 0;
+	
+	let err = loop {
+		let a = match iter.next() {
+			Some(a) => *a,
+			None => break None,
+		};
+		match (state, a) {
+			(State::Whole, b'-') | (State::Whole, b'+') if pos == 0 && T::SIGNED => negative = a == b'-',
+			_ if Some(a) == end => break None,
+			(_, b'0' ..= b'9') => {
+				let digit = a - b'0';
+				let next = current.checked_mul_radix(10).and_then(|c| match negative && state != State::Denominator {
+					true => c.checked_sub_digit(digit),
+					false => c.checked_add_digit(digit),
+				});
+				current = match next {
+					Some(c) => c,
+					None => break Some(AtoiErr::Overflow),
+				};
+				digits = true;
+				nonzero |= digit != 0;
+			},
+			(State::Whole, b' ') if digits => {
+				whole = current;
+				current = T::ZERO;
+				digits = false;
+				nonzero = false;
+				state = State::Numerator;
+			},
+			(State::Whole, b'/') | (State::Numerator, b'/') if digits => {
+				numerator = current;
+				current = T::ZERO;
+				digits = false;
+				nonzero = false;
+				state = State::Denominator;
+			},
+			_ => break Some(AtoiErr::ByteUnk(a)),
+		}
+		pos += 1;
+	};
+	
+	if let Some(err) = err {
+		atoi_skip_stop(iter, end, wait);
+		return Err(E::from_atoi_err(err, pos));
+	}
+	
+	let overflow = || E::from_atoi_err(AtoiErr::Overflow, pos);
+	let (numerator, denominator) = match state {
+		State::Whole => return T::ZERO.checked_add_digit(1).map(|one| (current, one)).ok_or_else(overflow),
+		State::Numerator => return Err(E::from_atoi_err(AtoiErr::MissingStop, pos)),
+		State::Denominator if !nonzero => return Err(E::from_atoi_err(AtoiErr::Zero, pos)),
+		State::Denominator => (numerator, current),
+	};
+	
+	let reduced = numerator.checked_gcd(&denominator).and_then(|divisor| {
+		let numerator = numerator.checked_div_exact(&divisor)?;
+		let denominator = denominator.checked_div_exact(&divisor)?;
+		Some((whole.checked_mul_add(&denominator, &numerator)?, denominator))
+	});
+	reduced.ok_or_else(overflow)
+}
+
+impl<T: AtoiNum> AtoiRatio<T> for T {
+	#[inline]
+	fn atoi_iter_ratio_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<(T, T), E> {
+		atoi_ratio(iter, None, false)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_ratio_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<(T, T), E> {
+		atoi_ratio(iter, Some(end), false)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_ratio_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<(T, T), E> {
+		atoi_ratio(iter, Some(end), true)
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use Atoi;
+	use I256;
+	use I512;
+	use U256;
+	
+	#[test]
+	fn atoi_ratio() {
+		assert_eq!(u32::atoi_ratio(b"3/4"), Result::Ok((3, 4)));
+		assert_eq!(i32::atoi_ratio(b"-7/2"), Result::Ok((-7, 2)));
+		assert_eq!(i32::atoi_ratio(b"+14/4"), Result::Ok((7, 2)));
+		assert_eq!(i32::atoi_ratio(b"-1 1/2"), Result::Ok((-3, 2)));
+		assert_eq!(u32::atoi_ratio(b"0/5"), Result::Ok((0, 1)));
+		assert_eq!(u32::atoi_ratio(b"2 0/5"), Result::Ok((2, 1)));
+		assert_eq!(u32::atoi_ratio(b"12"), Result::Ok((12, 1)));
+		assert_eq!(u32::atoi_ratio(b""), Result::Ok((0, 1)));
+		
+		assert_eq!(u32::atoi_ratio(b"1/0"), Result::Err(AtoiErr::Zero));
+		assert_eq!(u32::atoi_ratio(b"1/"), Result::Err(AtoiErr::Zero));
+		assert_eq!(u32::atoi_ratio(b"1 2"), Result::Err(AtoiErr::MissingStop));
+		assert_eq!(u32::atoi_ratio(b"1 "), Result::Err(AtoiErr::MissingStop));
+		assert_eq!(u32::atoi_ratio(b"1 /2"), Result::Err(AtoiErr::ByteUnk(b'/')));
+		assert_eq!(u32::atoi_ratio(b"/2"), Result::Err(AtoiErr::ByteUnk(b'/')));
+		assert_eq!(i32::atoi_ratio(b"-/2"), Result::Err(AtoiErr::ByteUnk(b'/')));
+		assert_eq!(u32::atoi_ratio(b"1/000"), Result::Err(AtoiErr::Zero));
+		assert_eq!(u32::atoi_ratio(b" 1/2"), Result::Err(AtoiErr::ByteUnk(b' ')));
+		assert_eq!(u32::atoi_ratio(b"1/ 2"), Result::Err(AtoiErr::ByteUnk(b' ')));
+		assert_eq!(u32::atoi_ratio(b"1/2/3"), Result::Err(AtoiErr::ByteUnk(b'/')));
+		assert_eq!(u32::atoi_ratio(b"1 1 1/2"), Result::Err(AtoiErr::ByteUnk(b' ')));
+		assert_eq!(i32::atoi_ratio(b"1/-2"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(u32::atoi_ratio(b"-1/2"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		
+		assert_eq!(i8::atoi_ratio(b"-128/1"), Result::Ok((i8::MIN, 1)));
+		assert_eq!(i8::atoi_ratio(b"128/1"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i8::atoi_ratio(b"1/128"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_ratio(b"1000/10"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_ratio(b"250/255"), Result::Ok((50, 51)));
+		assert_eq!(u8::atoi_ratio(b"127 1/2"), Result::Ok((255, 2)));
+		assert_eq!(u8::atoi_ratio(b"128 1/2"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i128::atoi_ratio(b"-170141183460469231731687303715884105728/1"), Result::Ok((i128::MIN, 1)));
+		assert_eq!(u128::atoi_ratio(b"340282366920938463463374607431768211455/5"), Result::Ok((u128::MAX / 5, 1)));
+		assert_eq!(u128::atoi_ratio(b"340282366920938463463374607431768211456/2"), Result::Err(AtoiErr::Overflow));
+		
+		//wide integers, the parts above 2^128
+		let big = format!("1{}", "0".repeat(60));
+		assert_eq!(U256::atoi_ratio(format!("{}/4{}", big, "0".repeat(59)).as_bytes()), Result::Ok((U256::from(5), U256::from(2))));
+		assert_eq!(I256::atoi_ratio(format!("-{0} {0}/3", big).as_bytes()), I256::atoi(format!("-4{}", "0".repeat(60)).as_bytes()).map(|a| (a, I256::from(3))));
+		assert_eq!(I256::atoi_ratio(b"-57896044618658097711785492504343953926634992332820282019728792003956564819968/2"),
+			I256::atoi(b"-28948022309329048855892746252171976963317496166410141009864396001978282409984").map(|a| (a, I256::from(1))));
+		assert_eq!(I512::atoi_ratio(format!("-{0}/{0}", big).as_bytes()), Result::Ok((I512::from(-1), I512::from(1))));
+		assert_eq!(U256::atoi_ratio(format!("{0}{0} 1/2", big).as_bytes()), Result::Err(AtoiErr::Overflow));
+	}
+	
+	#[test]
+	fn atoi_ratio_err() {
+		assert_eq!(i32::atoi_ratio_err::<(AtoiErr, usize)>(b"-1 1/"), Result::Err((AtoiErr::Zero, 5)));
+		assert_eq!(u8::atoi_ratio_err::<(AtoiErr, usize)>(b"1/256"), Result::Err((AtoiErr::Overflow, 4)));
+		assert_eq!(u32::atoi_stop_ratio_err::<(AtoiErr, usize)>(b"1 1/2", b' '), Result::Ok((1, 1)));
+		
+		//The quantities of a recipe, an error skips the rest of its field.
+		let array = b"2/4;1/0;1 2;/2;1x;5/1";
+		let mut iter = array.iter();
+		let mut quantities = Vec::new();
+		while iter.len() > 0 {
+			quantities.push(u32::atoi_iter_wait_stop_ratio_err::<(AtoiErr, usize), _>(&mut iter, b';'));
+		}
+		assert_eq!(quantities, vec![
+			Result::Ok((1, 2)),
+			Result::Err((AtoiErr::Zero, 3)),
+			Result::Err((AtoiErr::MissingStop, 3)),
+			Result::Err((AtoiErr::ByteUnk(b'/'), 0)),
+			Result::Err((AtoiErr::ByteUnk(b'x'), 1)),
+			Result::Ok((5, 1)),
+		]);
+	}
+}
@@ -0,0 +1,284 @@
+use AtoiErr;
+use AtoiNum;
+use AtoiExp;
+use AtoiResult;
+use FromAtoiErr;
+use atoi_skip_stop;
+
+///Parsing of the scientific notation `[+-]digits[.digits][(e|E)[+-]digits]` into the exact integer.
+///
+///The number must be integral (`AtoiErr::Inexact`) and fit into the type (`AtoiErr::Overflow`).
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::{AtoiSci, AtoiErr};
+///
+///fn main() {
+///	assert_eq!(u64::atoi_sci(b"1e6"), Ok(1_000_000));
+///	assert_eq!(i32::atoi_sci(b"-2.5E3"), Ok(-2500));
+///	assert_eq!(u32::atoi_sci(b"1500e-2"), Ok(15));
+///	assert_eq!(u32::atoi_sci(b"42"), Ok(42));
+///
+///	assert_eq!(u32::atoi_sci(b"2.5"), Err(AtoiErr::Inexact));
+///	assert_eq!(u8::atoi_sci(b"1e3"), Err(AtoiErr::Overflow));
+///	assert_eq!(u32::atoi_sci(b"1e"), Err(AtoiErr::MissingExponent));
+///}
+///```
+pub trait AtoiSci<T> {
+	///`Atoi::atoi` of the scientific notation.
+	#[inline]
+	fn atoi_sci(array: &[u8]) -> AtoiResult<T> {
+		Self::atoi_iter_sci(array.iter())
+	}
+	
+	///`Atoi::atoi_stop` of the scientific notation.
+	#[inline]
+	fn atoi_stop_sci(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atoi_iter_stop_sci(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_wait_stop` of the scientific notation.
+	#[inline]
+	fn atoi_wait_stop_sci(array: &[u8], stop: u8) -> AtoiResult<T> {
+		Self::atoi_iter_wait_stop_sci(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_err` of the scientific notation.
+	///
+	///`AtoiErr::Inexact`, `AtoiErr::Overflow` and `AtoiErr::MissingExponent` are known only at the end of the number, it is their position.
+	///
+	///```rust
+	///use cluatoi::{AtoiSci, AtoiErr};
+	///
+	///fn main() {
+	///	assert_eq!(u32::atoi_sci_err::<(AtoiErr, usize)>(b"2.5e0x"), Err((AtoiErr::ByteUnk(b'x'), 5)));
+	///	assert_eq!(u32::atoi_sci_err::<(AtoiErr, usize)>(b"2.5e0"), Err((AtoiErr::Inexact, 5)));
+	///}
+	///```
+	#[inline]
+	fn atoi_sci_err<E: FromAtoiErr>(array: &[u8]) -> Result<T, E> {
+		Self::atoi_iter_sci_err(array.iter())
+	}
+	
+	///`Atoi::atoi_stop_err` of the scientific notation.
+	#[inline]
+	fn atoi_stop_sci_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		Self::atoi_iter_stop_sci_err(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_wait_stop_err` of the scientific notation.
+	#[inline]
+	fn atoi_wait_stop_sci_err<E: FromAtoiErr>(array: &[u8], stop: u8) -> Result<T, E> {
+		Self::atoi_iter_wait_stop_sci_err(array.iter(), stop)
+	}
+	
+	///`Atoi::atoi_iter` of the scientific notation.
+	#[inline]
+	fn atoi_iter_sci<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<T> {
+		Self::atoi_iter_sci_err(iter)
+	}
+	
+	///`Atoi::atoi_iter_stop` of the scientific notation.
+	///
+	///```rust
+	///use cluatoi::AtoiSci;
+	///
+	///fn main() {
+	///	let array = b"1e3,2.5e1,7";
+	///	let mut array_iter = array.iter();
+	///
+	///	assert_eq!(u16::atoi_iter_stop_sci(&mut array_iter, b','), Ok(1000));
+	///	assert_eq!(u16::atoi_iter_stop_sci(&mut array_iter, b','), Ok(25));
+	///	assert_eq!(u16::atoi_iter_stop_sci(&mut array_iter, b','), Ok(7));
+	///}
+	///```
+	#[inline]
+	fn atoi_iter_stop_sci<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		Self::atoi_iter_stop_sci_err(iter, end)
+	}
+	
+	///`Atoi::atoi_iter_wait_stop` of the scientific notation.
+	#[inline]
+	fn atoi_iter_wait_stop_sci<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<T> {
+		Self::atoi_iter_wait_stop_sci_err(iter, end)
+	}
+	
+	///`Atoi::atoi_iter_err` of the scientific notation.
+	fn atoi_iter_sci_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, E>;
+	
+	///`Atoi::atoi_iter_stop_err` of the scientific notation.
+	fn atoi_iter_stop_sci_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E>;
+	
+	///`Atoi::atoi_iter_wait_stop_err` of the scientific notation.
+	fn atoi_iter_wait_stop_sci_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E>;
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+	Integer,
+	Fraction,
+	Exponent,
+	ExponentSign,
+	ExponentDigits,
+}
+
+//Multiplication by 10^count.
+fn mul_pow10<T: AtoiNum>(mut result: T, count: u64) -> Option<T> {
+	for _ in 0 .. count {
+		result = result.checked_mul_radix(10)?;
+	}
+	Some(result)
+}
+
+//The significant digits are accumulated without the trailing zeros, so the number is integral
+//if the exponent does not cut more digits than the trailing zeros.
+fn atoi_sci<'a, T, E, I>(mut iter: I, end: Option<u8>, wait: bool) -> Result<T, E>
+	where T: AtoiNum, E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let mut result = Some(T::ZERO);
+	let mut nonzero = false;
+	let mut negative = false;
+	let mut zeros = 0u64;
+	let mut fraction = 0u64;
+	let mut exponent = AtoiExp::default();
+	let mut state = State::Integer;
+	let mut pos = 0;
+	
+	let err = loop {
+		let a = match iter.next() {
+			Some(a) => *a,
+			None => break None,
+		};
+		match (state, a) {
+			(State::Integer, b'-') | (State::Integer, b'+') if pos == 0 && T::SIGNED => negative = a == b'-',
+			_ if Some(a) == end => break None,
+			(State::Integer, b'0' ..= b'9') | (State::Fraction, b'0' ..= b'9') => {
+				if state == State::Fraction {
+					fraction += 1;
+				}
+				match a {
+					b'0' => zeros += nonzero as u64,
+					_ => {
+						//An overflow is reported at the end, the number may still be non-integral.
+						let digit = a - b'0';
+						result = result.and_then(|r| mul_pow10(r, zeros + 1)).and_then(|r| match negative {
+							true => r.checked_sub_digit(digit),
+							false => r.checked_add_digit(digit),
+						});
+						nonzero = true;
+						zeros = 0;
+					},
+				}
+			},
+			(State::Integer, b'.') => state = State::Fraction,
+			(State::Integer, b'e') | (State::Integer, b'E') | (State::Fraction, b'e') | (State::Fraction, b'E') => state = State::Exponent,
+			(State::Exponent, b'-') | (State::Exponent, b'+') => {
+				exponent.negative = a == b'-';
+				state = State::ExponentSign;
+			},
+			(State::Exponent, b'0' ..= b'9') | (State::ExponentSign, b'0' ..= b'9') | (State::ExponentDigits, b'0' ..= b'9') => {
+				exponent.push_digit(a - b'0');
+				state = State::ExponentDigits;
+			},
+			_ => break Some(AtoiErr::ByteUnk(a)),
+		}
+		pos += 1;
+	};
+	
+	if let Some(err) = err {
+		atoi_skip_stop(iter, end, wait);
+		return Err(E::from_atoi_err(err, pos));
+	}
+	
+	match state {
+		State::Exponent | State::ExponentSign => return Err(E::from_atoi_err(AtoiErr::MissingExponent, pos)),
+		_ if !nonzero => return Ok(T::ZERO),
+		_ => {},
+	}
+	
+	//The power of ten of the accumulated digits.
+	let shift = exponent.add_to(zeros as i64 - fraction as i64);
+	match (result, shift < 0) {
+		(_, true) => Err(E::from_atoi_err(AtoiErr::Inexact, pos)),
+		(Some(result), false) => mul_pow10(result, shift as u64).ok_or_else(|| E::from_atoi_err(AtoiErr::Overflow, pos)),
+		(None, false) => Err(E::from_atoi_err(AtoiErr::Overflow, pos)),
+	}
+}
+
+impl<T: AtoiNum> AtoiSci<T> for T {
+	#[inline]
+	fn atoi_iter_sci_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<T, E> {
+		atoi_sci(iter, None, false)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_sci_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		atoi_sci(iter, Some(end), false)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_sci_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<T, E> {
+		atoi_sci(iter, Some(end), true)
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	#[test]
+	fn atoi_sci() {
+		assert_eq!(u64::atoi_sci(b"1e6"), Result::Ok(1_000_000));
+		assert_eq!(u64::atoi_sci(b"1E+6"), Result::Ok(1_000_000));
+		assert_eq!(i64::atoi_sci(b"-2.5e3"), Result::Ok(-2500));
+		assert_eq!(i64::atoi_sci(b"+2.50e1"), Result::Ok(25));
+		assert_eq!(u64::atoi_sci(b"120000e-4"), Result::Ok(12));
+		assert_eq!(u64::atoi_sci(b"0.00012e5"), Result::Ok(12));
+		assert_eq!(u64::atoi_sci(b"1.000"), Result::Ok(1));
+		assert_eq!(u64::atoi_sci(b"0e999999999999"), Result::Ok(0));
+		assert_eq!(u64::atoi_sci(b"0.5e-999999999999"), Result::Err(AtoiErr::Inexact));
+		assert_eq!(u64::atoi_sci(b""), Result::Ok(0));
+		
+		assert_eq!(u64::atoi_sci(b"120000e-5"), Result::Err(AtoiErr::Inexact));
+		assert_eq!(u64::atoi_sci(b"1.05e1"), Result::Err(AtoiErr::Inexact));
+		assert_eq!(u8::atoi_sci(b"2551e-1"), Result::Err(AtoiErr::Inexact));
+		assert_eq!(u8::atoi_sci(b"2.56e2"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u8::atoi_sci(b"2551e1"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u64::atoi_sci(b"1e999999999999"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(i8::atoi_sci(b"-1.28e2"), Result::Ok(i8::MIN));
+		assert_eq!(i8::atoi_sci(b"1.28e2"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u128::atoi_sci(b"3.40282366920938463463374607431768211455e38"), Result::Ok(u128::MAX));
+		
+		let zeros = "0".repeat(1_000_000);
+		assert_eq!(u8::atoi_sci(format!("1{}e-1000000", zeros).as_bytes()), Result::Ok(1));
+		assert_eq!(i32::atoi_sci(format!("-25{}e-1000000", zeros).as_bytes()), Result::Ok(-25));
+		assert_eq!(u64::atoi_sci(format!("0.{}12e1000002", zeros).as_bytes()), Result::Ok(12));
+		assert_eq!(u64::atoi_sci(format!("1{}e-1000001", zeros).as_bytes()), Result::Err(AtoiErr::Inexact));
+		assert_eq!(u64::atoi_sci(format!("0.{}1e999999999999999999999", zeros).as_bytes()), Result::Err(AtoiErr::Overflow));
+		assert_eq!(u64::atoi_sci(format!("1{}e-999999999999999999999", zeros).as_bytes()), Result::Err(AtoiErr::Inexact));
+		
+		assert_eq!(u64::atoi_sci(b"1e"), Result::Err(AtoiErr::MissingExponent));
+		assert_eq!(u64::atoi_sci(b"1e-"), Result::Err(AtoiErr::MissingExponent));
+		assert_eq!(u64::atoi_sci(b"-1"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(u64::atoi_sci(b"1e5.0"), Result::Err(AtoiErr::ByteUnk(b'.')));
+		assert_eq!(u64::atoi_sci(b"1e2e2"), Result::Err(AtoiErr::ByteUnk(b'e')));
+	}
+	
+	#[test]
+	fn atoi_sci_err() {
+		assert_eq!(u32::atoi_sci_err::<(AtoiErr, usize)>(b"1e2.5"), Result::Err((AtoiErr::ByteUnk(b'.'), 3)));
+		assert_eq!(u32::atoi_sci_err::<(AtoiErr, usize)>(b"1.5e"), Result::Err((AtoiErr::MissingExponent, 4)));
+		assert_eq!(u8::atoi_sci_err::<(AtoiErr, usize)>(b"3e2"), Result::Err((AtoiErr::Overflow, 3)));
+		assert_eq!(u32::atoi_stop_sci(b"2e 2", b' '), Result::Err(AtoiErr::MissingExponent));
+		
+		//A log line of the measurements, an error skips the rest of its field.
+		let array = b"1e2 1.5 1x 2e0";
+		let mut iter = array.iter();
+		let mut values = Vec::new();
+		while iter.len() > 0 {
+			values.push(u32::atoi_iter_wait_stop_sci_err::<(AtoiErr, usize), _>(&mut iter, b' '));
+		}
+		assert_eq!(values, vec![Result::Ok(100), Result::Err((AtoiErr::Inexact, 3)), Result::Err((AtoiErr::ByteUnk(b'x'), 1)), Result::Ok(2)]);
+	}
+}
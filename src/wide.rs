@@ -0,0 +1,702 @@
+use AtoiErr;
+use AtoiNum;
+use AtoiResult;
+use FromAtoiErr;
+use atoi_skip_stop;
+
+//a * m mod 2^(64 * N) and the carry.
+fn mul_wrap<const N: usize>(a: &[u64; N], m: u64) -> ([u64; N], bool) {
+	let mut result = [0; N];
+	let mut carry = 0;
+	for (r, a) in result.iter_mut().zip(a.iter()) {
+		let value = *a as u128 * m as u128 + carry;
+		*r = value as u64;
+		carry = value >> 64;
+	}
+	(result, carry != 0)
+}
+
+//a * m, None on overflow.
+#[inline]
+fn mul_small<const N: usize>(a: &[u64; N], m: u64) -> Option<[u64; N]> {
+	match mul_wrap(a, m) {
+		(a, false) => Some(a),
+		_ => None,
+	}
+}
+
+//(a * radix +- digit) mod 2^(64 * N)
+#[inline]
+fn push_wrap<const N: usize>(a: &[u64; N], radix: u8, digit: u8, negative: bool) -> [u64; N] {
+	let (a, _) = mul_wrap(a, radix as u64);
+	match negative {
+		true => sub_small(&a, digit as u64).0,
+		false => add_small(&a, digit as u64).0,
+	}
+}
+
+//a + b mod 2^(64 * N) and the carry.
+fn add_small<const N: usize>(a: &[u64; N], b: u64) -> ([u64; N], bool) {
+	let mut result = *a;
+	let mut carry = b;
+	for r in result.iter_mut() {
+		if carry == 0 {
+			break;
+		}
+		let (sum, c) = r.overflowing_add(carry);
+		*r = sum;
+		carry = c as u64;
+	}
+	(result, carry != 0)
+}
+
+//a - b mod 2^(64 * N) and the borrow.
+fn sub_small<const N: usize>(a: &[u64; N], b: u64) -> ([u64; N], bool) {
+	let mut result = *a;
+	let mut borrow = b;
+	for r in result.iter_mut() {
+		if borrow == 0 {
+			break;
+		}
+		let (diff, c) = r.overflowing_sub(borrow);
+		*r = diff;
+		borrow = c as u64;
+	}
+	(result, borrow != 0)
+}
+
+//-a mod 2^(64 * N)
+fn neg<const N: usize>(a: &[u64; N]) -> [u64; N] {
+	let mut result = [0; N];
+	for (r, a) in result.iter_mut().zip(a.iter()) {
+		*r = !*a;
+	}
+	add_small(&result, 1).0
+}
+
+#[inline]
+fn is_neg<const N: usize>(a: &[u64; N]) -> bool {
+	a[N - 1] >> 63 == 1
+}
+
+//|a| of the two's complement as the unsigned number.
+#[inline]
+fn abs<const N: usize>(a: &[u64; N]) -> [u64; N] {
+	match is_neg(a) {
+		true => neg(a),
+		false => *a,
+	}
+}
+
+//a + b mod 2^(64 * N) and the carry.
+fn add_wide<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+	let mut result = [0; N];
+	let mut carry = false;
+	for ((r, a), b) in result.iter_mut().zip(a.iter()).zip(b.iter()) {
+		let (sum, c1) = a.overflowing_add(*b);
+		let (sum, c2) = sum.overflowing_add(carry as u64);
+		*r = sum;
+		carry = c1 || c2;
+	}
+	(result, carry)
+}
+
+//a - b mod 2^(64 * N)
+fn sub_wide<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+	let mut result = [0; N];
+	let mut borrow = false;
+	for ((r, a), b) in result.iter_mut().zip(a.iter()).zip(b.iter()) {
+		let (diff, c1) = a.overflowing_sub(*b);
+		let (diff, c2) = diff.overflowing_sub(borrow as u64);
+		*r = diff;
+		borrow = c1 || c2;
+	}
+	result
+}
+
+//a * b, None on overflow.
+fn mul_wide<const N: usize>(a: &[u64; N], b: &[u64; N]) -> Option<[u64; N]> {
+	let mut result = [0; N];
+	for (i, a) in a.iter().enumerate().filter(|(_, a)| **a != 0) {
+		let mut carry = 0u128;
+		for (j, b) in b.iter().enumerate() {
+			let value = *a as u128 * *b as u128 + carry;
+			match i + j < N {
+				true => {
+					let sum = result[i + j] as u128 + (value as u64) as u128;
+					result[i + j] = sum as u64;
+					carry = (value >> 64) + (sum >> 64);
+				},
+				false if value != 0 => return None,
+				false => carry = 0,
+			}
+		}
+		if carry != 0 {
+			return None;
+		}
+	}
+	Some(result)
+}
+
+//a / b and a % b by the binary long division, b is not zero.
+fn div_rem_wide<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], [u64; N]) {
+	let mut quotient = [0; N];
+	let mut rem = [0; N];
+	for bit in (0 .. 64 * N).rev() {
+		//rem * 2 + the bit of a, the shifted out bit makes it larger than b.
+		let high = rem[N - 1] >> 63 == 1;
+		for i in (1 .. N).rev() {
+			rem[i] = rem[i] << 1 | rem[i - 1] >> 63;
+		}
+		rem[0] = rem[0] << 1 | a[bit / 64] >> (bit % 64) & 1;
+		if high || !lt(&rem, b) {
+			rem = sub_wide(&rem, b);
+			quotient[bit / 64] |= 1 << (bit % 64);
+		}
+	}
+	(quotient, rem)
+}
+
+//a < b
+fn lt<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+	for (a, b) in a.iter().rev().zip(b.iter().rev()) {
+		if a != b {
+			return a < b;
+		}
+	}
+	false
+}
+
+//Euclid on the magnitudes.
+fn gcd_wide<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+	let (mut a, mut b) = (*a, *b);
+	while b.iter().any(|b| *b != 0) {
+		let (_, r) = div_rem_wide(&a, &b);
+		a = b;
+		b = r;
+	}
+	a
+}
+
+fn hex_digit(a: u8) -> Option<u8> {
+	match a {
+		b'0' ..= b'9' => Some(a - b'0'),
+		b'a' ..= b'f' => Some(a - b'a' + 10),
+		b'A' ..= b'F' => Some(a - b'A' + 10),
+		_ => None,
+	}
+}
+
+//Hexadecimal digits with the byte rules of Atoi.
+fn wide_hex_err<'a, T, E, I>(mut iter: I, end: Option<u8>, wait: bool) -> Result<T, E>
+	where T: AtoiNum, E: FromAtoiErr, I: Iterator<Item=&'a u8> {
+	
+	let mut result = T::ZERO;
+	let mut negative = false;
+	let mut pos = 0;
+	while let Some(a) = iter.next() {
+		let a = *a;
+		let err = match a {
+			b'-' | b'+' if pos == 0 && T::SIGNED => {
+				negative = a == b'-';
+				None
+			},
+			_ if Some(a) == end => break,
+			_ => match hex_digit(a) {
+				Some(digit) => {
+					let next = result.checked_mul_radix(16).and_then(|s| match negative {
+						true => s.checked_sub_digit(digit),
+						false => s.checked_add_digit(digit),
+					});
+					match next {
+						Some(s) => {
+							result = s;
+							None
+						},
+						None => Some(AtoiErr::Overflow),
+					}
+				},
+				None => Some(AtoiErr::ByteUnk(a)),
+			},
+		};
+		
+		if let Some(err) = err {
+			atoi_skip_stop(iter, end, wait);
+			return Err(E::from_atoi_err(err, pos));
+		}
+		pos += 1;
+	}
+	
+	Ok(result)
+}
+
+macro_rules! wide_build {
+	//COMMON
+	($t:ident, $n:expr) => {
+		impl $t {
+			///Number from little-endian limbs.
+			#[inline]
+			pub const fn from_limbs(limbs: [u64; $n]) -> Self {
+				$t(limbs)
+			}
+			
+			///Little-endian limbs.
+			#[inline]
+			pub const fn limbs(&self) -> [u64; $n] {
+				self.0
+			}
+			
+			///Parsing of hexadecimal digits (`0-9`, `a-f`, `A-F`) without a prefix.
+			#[inline]
+			pub fn atoi_hex(array: &[u8]) -> AtoiResult<Self> {
+				wide_hex_err(array.iter(), None, false)
+			}
+			
+			///Parsing of hexadecimal digits up to the "X" character.
+			#[inline]
+			pub fn atoi_hex_stop(array: &[u8], end: u8) -> AtoiResult<Self> {
+				wide_hex_err(array.iter(), Some(end), false)
+			}
+			
+			///Parsing of hexadecimal digits waiting for an "X" character even if an error occurred.
+			#[inline]
+			pub fn atoi_hex_wait_stop(array: &[u8], end: u8) -> AtoiResult<Self> {
+				wide_hex_err(array.iter(), Some(end), true)
+			}
+			
+			///Parsing of hexadecimal digits using an iterator.
+			#[inline]
+			pub fn atoi_iter_hex<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<Self> {
+				wide_hex_err(iter, None, false)
+			}
+			
+			///Parsing of hexadecimal digits using an iterator up to the "X" character.
+			#[inline]
+			pub fn atoi_iter_hex_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Self> {
+				wide_hex_err(iter, Some(end), false)
+			}
+			
+			///Parsing of hexadecimal digits using an iterator and waiting for an "X" character even if an error occurred.
+			#[inline]
+			pub fn atoi_iter_hex_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Self> {
+				wide_hex_err(iter, Some(end), true)
+			}
+			
+			///Parsing of hexadecimal digits with the error type of the caller.
+			#[inline]
+			pub fn atoi_hex_err<E: FromAtoiErr>(array: &[u8]) -> Result<Self, E> {
+				wide_hex_err(array.iter(), None, false)
+			}
+			
+			///Parsing of hexadecimal digits up to the "X" character with the error type of the caller.
+			#[inline]
+			pub fn atoi_hex_stop_err<E: FromAtoiErr>(array: &[u8], end: u8) -> Result<Self, E> {
+				wide_hex_err(array.iter(), Some(end), false)
+			}
+			
+			///Parsing of hexadecimal digits waiting for an "X" character even if an error occurred with the error type of the caller.
+			#[inline]
+			pub fn atoi_hex_wait_stop_err<E: FromAtoiErr>(array: &[u8], end: u8) -> Result<Self, E> {
+				wide_hex_err(array.iter(), Some(end), true)
+			}
+			
+			///Parsing of hexadecimal digits using an iterator with the error type of the caller.
+			#[inline]
+			pub fn atoi_iter_hex_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<Self, E> {
+				wide_hex_err(iter, None, false)
+			}
+			
+			///Parsing of hexadecimal digits using an iterator up to the "X" character with the error type of the caller.
+			#[inline]
+			pub fn atoi_iter_hex_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Self, E> {
+				wide_hex_err(iter, Some(end), false)
+			}
+			
+			///Parsing of hexadecimal digits using an iterator and waiting for an "X" character even if an error occurred with the error type of the caller.
+			#[inline]
+			pub fn atoi_iter_hex_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Self, E> {
+				wide_hex_err(iter, Some(end), true)
+			}
+		}
+	};
+	
+	//UNSIGNED
+	(u, $t:ident, $n:expr) => {
+		wide_build!($t, $n);
+		
+		impl $t {
+			pub const ZERO: Self = $t([0; $n]);
+			pub const MAX: Self = $t([u64::MAX; $n]);
+			
+			///The number if it fits into `u128`.
+			pub fn to_u128(&self) -> Option<u128> {
+				match self.0[2..].iter().all(|a| *a == 0) {
+					true => Some((self.0[1] as u128) << 64 | self.0[0] as u128),
+					false => None,
+				}
+			}
+		}
+		
+		impl From<u128> for $t {
+			fn from(a: u128) -> Self {
+				let mut limbs = [0; $n];
+				limbs[0] = a as u64;
+				limbs[1] = (a >> 64) as u64;
+				$t(limbs)
+			}
+		}
+		
+		impl AtoiNum for $t {
+			const ZERO: Self = $t([0; $n]);
+			const SIGNED: bool = false;
+			
+			#[inline]
+			fn checked_mul_radix(&self, radix: u8) -> Option<Self> {
+				mul_small(&self.0, radix as u64).map($t)
+			}
+			#[inline]
+			fn checked_add_digit(&self, digit: u8) -> Option<Self> {
+				match add_small(&self.0, digit as u64) {
+					(a, false) => Some($t(a)),
+					_ => None,
+				}
+			}
+			#[inline]
+			fn checked_sub_digit(&self, digit: u8) -> Option<Self> {
+				match sub_small(&self.0, digit as u64) {
+					(a, false) => Some($t(a)),
+					_ => None,
+				}
+			}
+			#[inline]
+			fn saturating_bound(negative: bool) -> Option<Self> {
+				match negative {
+					true => Some(Self::ZERO),
+					false => Some(Self::MAX),
+				}
+			}
+			#[inline]
+			fn wrapping_push_digit(&self, radix: u8, digit: u8, negative: bool) -> Option<Self> {
+				Some($t(push_wrap(&self.0, radix, digit, negative)))
+			}
+			#[inline]
+			fn checked_gcd(&self, denominator: &Self) -> Option<Self> {
+				Some($t(gcd_wide(&self.0, &denominator.0)))
+			}
+			fn checked_div_exact(&self, divisor: &Self) -> Option<Self> {
+				match divisor.0.iter().any(|a| *a != 0) {
+					true => Some($t(div_rem_wide(&self.0, &divisor.0).0)),
+					false => None,
+				}
+			}
+			fn checked_mul_add(&self, mul: &Self, add: &Self) -> Option<Self> {
+				match add_wide(&mul_wide(&self.0, &mul.0)?, &add.0) {
+					(a, false) => Some($t(a)),
+					_ => None,
+				}
+			}
+		}
+		
+		atoi_build_num!($t);
+	};
+	
+	//SIGNED, TWO'S COMPLEMENT
+	(i, $t:ident, $n:expr) => {
+		wide_build!($t, $n);
+		
+		impl $t {
+			pub const ZERO: Self = $t([0; $n]);
+			pub const MAX: Self = {
+				let mut limbs = [u64::MAX; $n];
+				limbs[$n - 1] = i64::MAX as u64;
+				$t(limbs)
+			};
+			pub const MIN: Self = {
+				let mut limbs = [0; $n];
+				limbs[$n - 1] = 1 << 63;
+				$t(limbs)
+			};
+			
+			#[inline]
+			pub fn is_negative(&self) -> bool {
+				is_neg(&self.0)
+			}
+			
+			///The number if it fits into `i128`.
+			pub fn to_i128(&self) -> Option<i128> {
+				let fill = match self.is_negative() {
+					true => u64::MAX,
+					false => 0,
+				};
+				let value = (self.0[1] as u128) << 64 | self.0[0] as u128;
+				match self.0[2..].iter().all(|a| *a == fill) && ((value as i128) < 0) == self.is_negative() {
+					true => Some(value as i128),
+					false => None,
+				}
+			}
+		}
+		
+		impl From<i128> for $t {
+			fn from(a: i128) -> Self {
+				let mut limbs = match a < 0 {
+					true => [u64::MAX; $n],
+					false => [0; $n],
+				};
+				limbs[0] = a as u64;
+				limbs[1] = (a >> 64) as u64;
+				$t(limbs)
+			}
+		}
+		
+		impl AtoiNum for $t {
+			const ZERO: Self = $t([0; $n]);
+			const SIGNED: bool = true;
+			
+			fn checked_mul_radix(&self, radix: u8) -> Option<Self> {
+				match self.is_negative() {
+					//-2^(64 * N - 1) is the largest magnitude.
+					true => match mul_small(&neg(&self.0), radix as u64) {
+						Some(a) if !is_neg(&a) => Some($t(neg(&a))),
+						Some(a) if a == Self::MIN.0 => Some(Self::MIN),
+						_ => None,
+					},
+					false => match mul_small(&self.0, radix as u64) {
+						Some(a) if !is_neg(&a) => Some($t(a)),
+						_ => None,
+					},
+				}
+			}
+			#[inline]
+			fn checked_add_digit(&self, digit: u8) -> Option<Self> {
+				let (a, _) = add_small(&self.0, digit as u64);
+				match !self.is_negative() && is_neg(&a) {
+					true => None,
+					false => Some($t(a)),
+				}
+			}
+			#[inline]
+			fn checked_sub_digit(&self, digit: u8) -> Option<Self> {
+				let (a, _) = sub_small(&self.0, digit as u64);
+				match self.is_negative() && !is_neg(&a) {
+					true => None,
+					false => Some($t(a)),
+				}
+			}
+			#[inline]
+			fn saturating_bound(negative: bool) -> Option<Self> {
+				match negative {
+					true => Some(Self::MIN),
+					false => Some(Self::MAX),
+				}
+			}
+			#[inline]
+			fn wrapping_push_digit(&self, radix: u8, digit: u8, negative: bool) -> Option<Self> {
+				Some($t(push_wrap(&self.0, radix, digit, negative)))
+			}
+			#[inline]
+			fn checked_gcd(&self, denominator: &Self) -> Option<Self> {
+				//Not above the positive denominator.
+				Some($t(gcd_wide(&abs(&self.0), &denominator.0)))
+			}
+			fn checked_div_exact(&self, divisor: &Self) -> Option<Self> {
+				if divisor.0.iter().all(|a| *a == 0) {
+					return None;
+				}
+				let (a, _) = div_rem_wide(&abs(&self.0), &abs(&divisor.0));
+				match (self.is_negative() != divisor.is_negative(), is_neg(&a)) {
+					(true, _) => Some($t(neg(&a))),
+					(false, false) => Some($t(a)),
+					//MIN / -1
+					(false, true) => None,
+				}
+			}
+			fn checked_mul_add(&self, mul: &Self, add: &Self) -> Option<Self> {
+				let a = mul_wide(&abs(&self.0), &abs(&mul.0))?;
+				let a = match (self.is_negative() != mul.is_negative(), is_neg(&a)) {
+					(true, false) => neg(&a),
+					(true, true) if a == Self::MIN.0 => a,
+					(false, false) => a,
+					_ => return None,
+				};
+				//The overflow changes the sign of two numbers with the same sign.
+				let (sum, _) = add_wide(&a, &add.0);
+				match is_neg(&a) == add.is_negative() && is_neg(&sum) != is_neg(&a) {
+					true => None,
+					false => Some($t(sum)),
+				}
+			}
+		}
+		
+		atoi_build_num!($t);
+	};
+}
+
+///Unsigned 256-bit integer without allocation (`uint256`), little-endian `u64` limbs.
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::Atoi;
+///use cluatoi::U256;
+///
+///fn main() {
+///	let num = U256::atoi(b"115792089237316195423570985008687907853269984665640564039457584007913129639935").unwrap();
+///	assert_eq!(num, U256::MAX);
+///
+///	let num = U256::atoi_hex_stop(b"10000000000000000;", b';').unwrap();
+///	assert_eq!(num.limbs(), [0, 1, 0, 0]);
+///}
+///```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct U256([u64; 4]);
+
+///Unsigned 512-bit integer without allocation, little-endian `u64` limbs.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct U512([u64; 8]);
+
+///Signed 256-bit integer without allocation (`int256`), two's complement in little-endian `u64` limbs.
+///
+///```rust
+///extern crate cluatoi;
+///use cluatoi::Atoi;
+///use cluatoi::I256;
+///
+///fn main() {
+///	let array = b"-ff!-1!";
+///	let mut array_iter = array.iter();
+///
+///	assert_eq!(I256::atoi_iter_hex_stop(&mut array_iter, b'!'), Ok(I256::from(-255)));
+///	assert_eq!(I256::atoi_iter_stop(&mut array_iter, b'!'), Ok(I256::from(-1)));
+///}
+///```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct I256([u64; 4]);
+
+///Signed 512-bit integer without allocation, two's complement in little-endian `u64` limbs.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct I512([u64; 8]);
+
+wide_build!(u, U256, 4);
+wide_build!(u, U512, 8);
+wide_build!(i, I256, 4);
+wide_build!(i, I512, 8);
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use Atoi;
+	
+	#[test]
+	fn wide_unsigned() {
+		assert_eq!(U256::atoi(b"0"), Result::Ok(U256::ZERO));
+		assert_eq!(U256::atoi(b"340282366920938463463374607431768211456").map(|a| a.limbs()), Result::Ok([0, 0, 1, 0]));
+		assert_eq!(U256::atoi(b"115792089237316195423570985008687907853269984665640564039457584007913129639935"), Result::Ok(U256::MAX));
+		assert_eq!(U256::atoi(b"115792089237316195423570985008687907853269984665640564039457584007913129639936"), Result::Err(AtoiErr::Overflow));
+		assert_eq!(U256::atoi(b"-1"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(U512::atoi(b"18446744073709551615").map(|a| a.to_u128()), Result::Ok(Some(u64::MAX as u128)));
+		
+		assert_eq!(U256::atoi_hex(&[b'F'; 64]), Result::Ok(U256::MAX));
+		assert_eq!(U256::atoi_hex(&[b'f'; 65]), Result::Err(AtoiErr::Overflow));
+		assert_eq!(U512::atoi_hex(b"1").map(|a| a.limbs()), Result::Ok([1, 0, 0, 0, 0, 0, 0, 0]));
+		assert_eq!(U256::atoi_hex(b"1g"), Result::Err(AtoiErr::ByteUnk(b'g')));
+	}
+	
+	#[test]
+	fn wide_signed() {
+		let mut max = b"5789604461865809771178549250434395392663499233282028201972879200395656481996".to_vec();
+		assert_eq!(I256::atoi(&[&max[..], b"7"].concat()), Result::Ok(I256::MAX));
+		assert_eq!(I256::atoi(&[&max[..], b"8"].concat()), Result::Err(AtoiErr::Overflow));
+		max.insert(0, b'-');
+		assert_eq!(I256::atoi(&[&max[..], b"8"].concat()), Result::Ok(I256::MIN));
+		assert_eq!(I256::atoi(&[&max[..], b"9"].concat()), Result::Err(AtoiErr::Overflow));
+		
+		assert_eq!(I256::atoi(b"-170141183460469231731687303715884105728").map(|a| a.to_i128()), Result::Ok(Some(i128::MIN)));
+		assert_eq!(I256::atoi(b"-170141183460469231731687303715884105729").map(|a| a.to_i128()), Result::Ok(None));
+		assert_eq!(I512::atoi(b"+42"), Result::Ok(I512::from(42)));
+		
+		assert_eq!(I256::atoi_hex(&[b"-8", &[b'0'; 63][..]].concat()), Result::Ok(I256::MIN));
+		assert_eq!(I256::atoi_hex(&[b"8", &[b'0'; 63][..]].concat()), Result::Err(AtoiErr::Overflow));
+	}
+	
+	#[test]
+	fn wide_arith() {
+		let mut state = 0x9E37_79B9_7F4A_7C15u64;
+		let mut next = || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		};
+		//Against u128 and i128 on the values of 64 bits.
+		for _ in 0 .. 1_000 {
+			let (a, b, c) = (next(), next() >> (next() % 64), next());
+			let (ua, ub, uc) = (U256::from(a as u128), U256::from(b as u128 + 1), U256::from(c as u128));
+			assert_eq!(ua.checked_mul_add(&ub, &uc), Some(U256::from(a as u128 * (b as u128 + 1) + c as u128)));
+			assert_eq!(ua.checked_div_exact(&ub), Some(U256::from(a as u128 / (b as u128 + 1))));
+			
+			let (mut x, mut y) = (a as u128, b as u128 + 1);
+			while y != 0 {
+				let r = x % y;
+				x = y;
+				y = r;
+			}
+			assert_eq!(ua.checked_gcd(&ub), Some(U256::from(x)));
+			
+			let (a, b, c) = (a as i64 as i128, (b as i64 >> 1) as i128, c as i64 as i128);
+			let (ia, ib, ic) = (I512::from(a), I512::from(b), I512::from(c));
+			assert_eq!(ia.checked_mul_add(&ib, &ic), Some(I512::from(a * b + c)));
+			if b != 0 {
+				assert_eq!(ia.checked_div_exact(&ib), Some(I512::from(a / b)));
+			}
+		}
+		
+		let pow = |n: usize| {
+			let mut limbs = [0; 4];
+			limbs[n / 64] = 1 << (n % 64);
+			U256(limbs)
+		};
+		assert_eq!(pow(127).checked_mul_add(&pow(128), &U256::ZERO), Some(pow(255)));
+		assert_eq!(pow(128).checked_mul_add(&pow(128), &U256::ZERO), None);
+		assert_eq!(U256::MAX.checked_mul_add(&U256::from(1), &U256::from(1)), None);
+		assert_eq!(U256::MAX.checked_div_exact(&pow(255)), Some(U256::from(1)));
+		assert_eq!(U256::MAX.checked_div_exact(&U256::ZERO), None);
+		
+		let one = I256::from(1);
+		assert_eq!(I256::MIN.checked_mul_add(&one, &I256::ZERO), Some(I256::MIN));
+		assert_eq!(I256::MIN.checked_mul_add(&I256::from(-1), &I256::ZERO), None);
+		assert_eq!(I256::MAX.checked_mul_add(&I256::from(-1), &I256::from(-1)), Some(I256::MIN));
+		assert_eq!(I256::MAX.checked_mul_add(&one, &one), None);
+		assert_eq!(I256::MIN.checked_div_exact(&I256::from(-1)), None);
+		assert_eq!(I256::MIN.checked_div_exact(&I256::MIN), Some(one));
+		assert_eq!(I256::MIN.checked_gcd(&I256::MAX), Some(one));
+	}
+	
+	#[test]
+	fn wide_stop() {
+		let array = b"12!-ff!1z!7";
+		let mut iter = array.iter();
+		assert_eq!(I512::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(I512::from(12)));
+		assert_eq!(I512::atoi_iter_hex_wait_stop(&mut iter, b'!'), Result::Ok(I512::from(-255)));
+		assert_eq!(I512::atoi_iter_hex_wait_stop(&mut iter, b'!'), Result::Err(AtoiErr::ByteUnk(b'z')));
+		assert_eq!(I512::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(I512::from(7)));
+		
+		assert_eq!(U256::atoi_err::<(AtoiErr, usize)>(b"12x"), Result::Err((AtoiErr::ByteUnk(b'x'), 2)));
+	}
+	
+	#[test]
+	fn wide_hex() {
+		type PosErr = (AtoiErr, usize);
+		
+		assert_eq!(U256::atoi_hex_wait_stop(b"1g!", b'!'), Result::Err(AtoiErr::ByteUnk(b'g')));
+		assert_eq!(U256::atoi_hex_err::<PosErr>(b"ffz"), Result::Err((AtoiErr::ByteUnk(b'z'), 2)));
+		assert_eq!(U256::atoi_hex_err::<PosErr>(&[b'f'; 65]), Result::Err((AtoiErr::Overflow, 64)));
+		assert_eq!(I256::atoi_hex_stop_err::<PosErr>(b"-10!", b'!'), Result::Ok(I256::from(-16)));
+		assert_eq!(U512::atoi_hex_wait_stop_err::<PosErr>(b"-1!", b'!'), Result::Err((AtoiErr::ByteUnk(b'-'), 0)));
+		
+		let array = b"a!x1!-B";
+		let mut iter = array.iter();
+		assert_eq!(I512::atoi_iter_hex_stop_err::<PosErr, _>(&mut iter, b'!'), Result::Ok(I512::from(10)));
+		assert_eq!(I512::atoi_iter_hex_wait_stop_err::<PosErr, _>(&mut iter, b'!'), Result::Err((AtoiErr::ByteUnk(b'x'), 0)));
+		assert_eq!(I512::atoi_iter_hex_err::<PosErr, _>(&mut iter), Result::Ok(I512::from(-11)));
+	}
+}
@@ -0,0 +1,135 @@
+use std::num::{Saturating, Wrapping};
+use Atoi;
+use AtoiOverflow;
+use AtoiPolicy;
+use AtoiResult;
+use FromAtoiErr;
+
+///Parsing modulo 2^N, overflow of number is not an error.
+///
+///```rust
+///use std::num::Wrapping;
+///use cluatoi::Atoi;
+///
+///fn main() {
+///	assert_eq!(Wrapping::<u8>::atoi(b"257"), Ok(Wrapping(1)));
+///	assert_eq!(Wrapping::<i8>::atoi(b"-129"), Ok(Wrapping(127)));
+///}
+///```
+impl<T: AtoiPolicy<T>> Atoi<Wrapping<T>> for Wrapping<T> {
+	#[inline]
+	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<Wrapping<T>> {
+		Self::atoi_iter_err(iter)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Wrapping<T>> {
+		Self::atoi_iter_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Wrapping<T>> {
+		Self::atoi_iter_wait_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<Wrapping<T>, E> {
+		T::atoi_iter_policy_err(iter, AtoiOverflow::Wrap).map(|(num, _)| Wrapping(num))
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Wrapping<T>, E> {
+		T::atoi_iter_stop_policy_err(iter, end, AtoiOverflow::Wrap).map(|(num, _)| Wrapping(num))
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Wrapping<T>, E> {
+		T::atoi_iter_wait_stop_policy_err(iter, end, AtoiOverflow::Wrap).map(|(num, _)| Wrapping(num))
+	}
+}
+
+///Parsing with clamping to `T::MAX`/`T::MIN`, overflow of number is not an error.
+///
+///```rust
+///use std::num::Saturating;
+///use cluatoi::Atoi;
+///
+///fn main() {
+///	assert_eq!(Saturating::<u8>::atoi(b"257"), Ok(Saturating(255)));
+///	assert_eq!(Saturating::<i8>::atoi(b"-129"), Ok(Saturating(-128)));
+///}
+///```
+impl<T: AtoiPolicy<T>> Atoi<Saturating<T>> for Saturating<T> {
+	#[inline]
+	fn atoi_iter<'a, I: Iterator<Item=&'a u8>>(iter: I) -> AtoiResult<Saturating<T>> {
+		Self::atoi_iter_err(iter)
+	}
+	
+	#[inline]
+	fn atoi_iter_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Saturating<T>> {
+		Self::atoi_iter_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop<'a, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> AtoiResult<Saturating<T>> {
+		Self::atoi_iter_wait_stop_err(iter, end)
+	}
+	
+	#[inline]
+	fn atoi_iter_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I) -> Result<Saturating<T>, E> {
+		T::atoi_iter_policy_err(iter, AtoiOverflow::Saturate).map(|(num, _)| Saturating(num))
+	}
+	
+	#[inline]
+	fn atoi_iter_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Saturating<T>, E> {
+		T::atoi_iter_stop_policy_err(iter, end, AtoiOverflow::Saturate).map(|(num, _)| Saturating(num))
+	}
+	
+	#[inline]
+	fn atoi_iter_wait_stop_err<'a, E: FromAtoiErr, I: Iterator<Item=&'a u8>>(iter: I, end: u8) -> Result<Saturating<T>, E> {
+		T::atoi_iter_wait_stop_policy_err(iter, end, AtoiOverflow::Saturate).map(|(num, _)| Saturating(num))
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::num::NonZeroU8;
+	use AtoiErr;
+	
+	#[test]
+	fn wrapping() {
+		assert_eq!(Wrapping::<u8>::atoi(b"255"), Result::Ok(Wrapping(255)));
+		assert_eq!(Wrapping::<u8>::atoi(b"256"), Result::Ok(Wrapping(0)));
+		assert_eq!(Wrapping::<u32>::atoi(b"4294967297"), Result::Ok(Wrapping(1)));
+		assert_eq!(Wrapping::<i16>::atoi(b"32768"), Result::Ok(Wrapping(-32768)));
+		assert_eq!(Wrapping::<u128>::atoi(b"340282366920938463463374607431768211457"), Result::Ok(Wrapping(1)));
+		assert_eq!(Wrapping::<usize>::atoi(b"-1"), Result::Err(AtoiErr::ByteUnk(b'-')));
+		assert_eq!(Wrapping::<i64>::atoi_err::<(AtoiErr, usize)>(b"-99999999999999999999x"), Result::Err((AtoiErr::ByteUnk(b'x'), 21)));
+	}
+	
+	#[test]
+	fn saturating() {
+		assert_eq!(Saturating::<u8>::atoi(b"99999"), Result::Ok(Saturating(255)));
+		assert_eq!(Saturating::<i32>::atoi(b"-99999999999"), Result::Ok(Saturating(i32::MIN)));
+		assert_eq!(Saturating::<isize>::atoi(b"+1"), Result::Ok(Saturating(1)));
+		assert_eq!(Saturating::<u8>::atoi(b"1.5"), Result::Err(AtoiErr::ByteUnk(b'.')));
+		
+		//the error before the first byte
+		assert_eq!(Saturating::<NonZeroU8>::atoi_err::<(AtoiErr, usize)>(b""), Result::Err((AtoiErr::Zero, 0)));
+		assert_eq!(Wrapping::<NonZeroU8>::atoi_stop_err::<(AtoiErr, usize)>(b"!", b'!'), Result::Err((AtoiErr::Zero, 0)));
+		assert_eq!(Saturating::<NonZeroU8>::atoi(b"999"), Result::Ok(Saturating(NonZeroU8::new(255).unwrap())));
+	}
+	
+	#[test]
+	fn wait_stop() {
+		let array = b"999!9x9!7";
+		let mut iter = array.iter();
+		
+		assert_eq!(Saturating::<u8>::atoi_iter_wait_stop(&mut iter, b'!'), Result::Ok(Saturating(255)));
+		assert_eq!(Wrapping::<u8>::atoi_iter_wait_stop_err::<(AtoiErr, usize), _>(&mut iter, b'!'), Result::Err((AtoiErr::ByteUnk(b'x'), 1)));
+		assert_eq!(Wrapping::<u8>::atoi_iter_stop(&mut iter, b'!'), Result::Ok(Wrapping(7)));
+		assert_eq!(iter.next(), None);
+	}
+}